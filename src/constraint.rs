@@ -1,11 +1,109 @@
+use crate::constraints::{
+    AllEqualRadiusConstraint, AngleRangeConstraint, ArcSweepConstraint, ArcTangentConstraint,
+    BoltCircleConstraint, ChordLengthConstraint, ChordOnLineConstraint, CircleNonOverlapConstraint,
+    CircleRadiusConstraint, CircumcenterConstraint, ClosedLoopConstraint, CodirectionalConstraint,
+    CoincidentPointsConstraint, ConcyclicConstraint, ConvexPolygonConstraint,
+    CornerAngleConstraint, DiameterConstraint, DistanceAlongLineConstraint,
+    EllipseAspectRatioConstraint, EllipseSemiAxesConstraint, EqualDiagonalsConstraint,
+    EqualInclinationConstraint, EqualOffsetConstraint, FixedCentroidConstraint,
+    FixedPositionConstraint, HorizontalDistanceConstraint, InclinationConstraint,
+    LineLengthConstraint, LineLengthFromCircumferenceConstraint, LineSymmetryConstraint,
+    LinearLengthRelationConstraint, MaxLengthConstraint, MidpointConstraint,
+    MidpointOnLineConstraint, MinAreaConstraint, OffsetLineConstraint, OnAngleBisectorConstraint,
+    OrbitConstraint, ParallelLinesConstraint, ParallelogramConstraint, PerimeterConstraint,
+    PerpendicularAtEndpointConstraint, PerpendicularLinesConstraint,
+    PointAtCircleIntersectionConstraint, PointLineDistanceConstraint,
+    PointOnCircleAtAngleConstraint, PointOnLineConstraint, PointSideOfLineConstraint,
+    ProjectionConstraint, ProportionalPointConstraint, RadialSymmetryConstraint,
+    RadiusEqualsLineLengthConstraint, RhombusConstraint, SegmentDivisionConstraint,
+    SymmetryConstraint, TangentJoinConstraint, ThroughMidpointConstraint, TriangleAreaConstraint,
+    VerticalDistanceConstraint,
+};
 use crate::entities::PointId;
-use crate::entity::{CircleId, LineId};
-use crate::error::Result;
+use crate::entity::{ArcId, CircleId, EllipseId, LineId};
+use crate::error::{Result, TextCadError};
+use std::collections::{BTreeMap, BTreeSet};
 use z3::ast::Real;
 
+/// Identifier for a constraint within a sketch
+///
+/// Wraps the position of the constraint in the order it was added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConstraintId(pub usize);
+
+/// Reference to a sketch entity touched by a constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EntityRef {
+    /// A point entity
+    Point(PointId),
+    /// A line entity
+    Line(LineId),
+    /// A circle entity
+    Circle(CircleId),
+    /// An arc entity
+    Arc(ArcId),
+    /// An ellipse entity
+    Ellipse(EllipseId),
+}
+
+impl From<LineId> for EntityRef {
+    fn from(id: LineId) -> Self {
+        EntityRef::Line(id)
+    }
+}
+
+impl From<CircleId> for EntityRef {
+    fn from(id: CircleId) -> Self {
+        EntityRef::Circle(id)
+    }
+}
+
+/// Adjacency between constraints and the entities they reference
+///
+/// Built by `Sketch::constraint_graph`. Each constraint maps to the entities
+/// it names directly plus the points those entities are built from (line
+/// endpoints, circle and ellipse centers, and
+/// arc endpoints and circles).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintGraph {
+    /// Referenced entities per constraint, keyed in insertion order
+    pub edges: BTreeMap<ConstraintId, BTreeSet<EntityRef>>,
+}
+
+impl ConstraintGraph {
+    /// Get the entities referenced by a constraint
+    pub fn entities(&self, id: ConstraintId) -> Option<&BTreeSet<EntityRef>> {
+        self.edges.get(&id)
+    }
+
+    /// Get all constraints that reference the given entity
+    pub fn constraints_touching(&self, entity: EntityRef) -> Vec<ConstraintId> {
+        self.edges
+            .iter()
+            .filter(|(_, entities)| entities.contains(&entity))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
+
+/// Upcast to a `&dyn Constraint`
+///
+/// Implemented for every sized `Constraint`, so that provided methods of
+/// `Constraint` can hand `self` to code expecting a trait object.
+pub trait AsDynConstraint {
+    /// View this value as a constraint trait object
+    fn as_dyn_constraint(&self) -> &dyn Constraint;
+}
+
+impl<T: Constraint> AsDynConstraint for T {
+    fn as_dyn_constraint(&self) -> &dyn Constraint {
+        self
+    }
+}
+
 /// Trait for constraints that can be applied to a Z3 solver context.
 /// Each constraint knows how to translate itself into Z3 assertions.
-pub trait Constraint: Send + Sync + std::fmt::Debug {
+pub trait Constraint: AsDynConstraint + Send + Sync + std::fmt::Debug {
     /// Apply this constraint to the solver by adding the necessary Z3 assertions.
     ///
     /// # Arguments
@@ -21,6 +119,226 @@ pub trait Constraint: Send + Sync + std::fmt::Debug {
 
     /// Get a human-readable description of this constraint for debugging
     fn description(&self) -> String;
+
+    /// Dispatch this constraint to the matching method of a visitor
+    ///
+    /// Built-in constraints call their specific `visit_*` method. Defaults to
+    /// `ConstraintVisitor::visit_other`, which suits custom constraints.
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_other(self.as_dyn_constraint());
+    }
+
+    /// Entities this constraint names directly
+    ///
+    /// Used by `Sketch::constraint_graph`. Defaults to none, so custom
+    /// constraints that do not override it are left out of the graph.
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        Vec::new()
+    }
+
+    /// Whether every assertion this constraint adds is linear in the sketch variables
+    ///
+    /// Used as a difficulty heuristic by `Sketch::complexity_estimate`.
+    /// Defaults to `false`, the conservative answer for custom constraints.
+    fn is_linear(&self) -> bool {
+        false
+    }
+}
+
+/// Visitor over the concrete constraint types
+///
+/// Allows analysis passes (counting constraint kinds, collecting referenced
+/// entities, ...) without downcasting boxed constraints. Every method has an
+/// empty default implementation, so visitors only override what they need.
+pub trait ConstraintVisitor {
+    /// Visit a coincident points constraint
+    fn visit_coincident(&mut self, _constraint: &CoincidentPointsConstraint) {}
+
+    /// Visit a fixed position constraint
+    fn visit_fixed(&mut self, _constraint: &FixedPositionConstraint) {}
+
+    /// Visit a horizontal distance constraint
+    fn visit_horizontal_distance(&mut self, _constraint: &HorizontalDistanceConstraint) {}
+
+    /// Visit a vertical distance constraint
+    fn visit_vertical_distance(&mut self, _constraint: &VerticalDistanceConstraint) {}
+
+    /// Visit an equal offset constraint
+    fn visit_equal_offset(&mut self, _constraint: &EqualOffsetConstraint) {}
+
+    /// Visit an orbit constraint
+    fn visit_orbit(&mut self, _constraint: &OrbitConstraint) {}
+
+    /// Visit a circumcenter constraint
+    fn visit_circumcenter(&mut self, _constraint: &CircumcenterConstraint) {}
+
+    /// Visit a fixed centroid constraint
+    fn visit_fixed_centroid(&mut self, _constraint: &FixedCentroidConstraint) {}
+
+    /// Visit a line length constraint
+    fn visit_line_length(&mut self, _constraint: &LineLengthConstraint) {}
+
+    /// Visit a maximum line length constraint
+    fn visit_max_length(&mut self, _constraint: &MaxLengthConstraint) {}
+
+    /// Visit a through-midpoint constraint
+    fn visit_through_midpoint(&mut self, _constraint: &ThroughMidpointConstraint) {}
+
+    /// Visit a linear length relation constraint
+    fn visit_linear_length_relation(&mut self, _constraint: &LinearLengthRelationConstraint) {}
+
+    /// Visit an offset line constraint
+    fn visit_offset_line(&mut self, _constraint: &OffsetLineConstraint) {}
+
+    /// Visit a parallel lines constraint
+    fn visit_parallel(&mut self, _constraint: &ParallelLinesConstraint) {}
+
+    /// Visit a codirectional lines constraint
+    fn visit_codirectional(&mut self, _constraint: &CodirectionalConstraint) {}
+
+    /// Visit an inclination-from-vertical constraint
+    fn visit_inclination(&mut self, _constraint: &InclinationConstraint) {}
+
+    /// Visit an equal inclination constraint
+    fn visit_equal_inclination(&mut self, _constraint: &EqualInclinationConstraint) {}
+
+    /// Visit an angle range constraint
+    fn visit_angle_range(&mut self, _constraint: &AngleRangeConstraint) {}
+
+    /// Visit a perpendicular lines constraint
+    fn visit_perpendicular(&mut self, _constraint: &PerpendicularLinesConstraint) {}
+
+    /// Visit a corner angle constraint
+    fn visit_corner_angle(&mut self, _constraint: &CornerAngleConstraint) {}
+
+    /// Visit a point-on-line constraint
+    fn visit_point_on_line(&mut self, _constraint: &PointOnLineConstraint) {}
+
+    /// Visit a midpoint constraint
+    fn visit_midpoint(&mut self, _constraint: &MidpointConstraint) {}
+
+    /// Visit a perpendicular-at-endpoint constraint
+    fn visit_perpendicular_at_endpoint(&mut self, _constraint: &PerpendicularAtEndpointConstraint) {
+    }
+
+    /// Visit a midpoint-on-line constraint
+    fn visit_midpoint_on_line(&mut self, _constraint: &MidpointOnLineConstraint) {}
+
+    /// Visit a projection constraint
+    fn visit_projection(&mut self, _constraint: &ProjectionConstraint) {}
+
+    /// Visit a point-to-line distance constraint
+    fn visit_point_line_distance(&mut self, _constraint: &PointLineDistanceConstraint) {}
+
+    /// Visit a segment division constraint
+    fn visit_segment_division(&mut self, _constraint: &SegmentDivisionConstraint) {}
+
+    /// Visit a proportional point constraint
+    fn visit_proportional_point(&mut self, _constraint: &ProportionalPointConstraint) {}
+
+    /// Visit a distance-along-line constraint
+    fn visit_distance_along_line(&mut self, _constraint: &DistanceAlongLineConstraint) {}
+
+    /// Visit an on-angle-bisector constraint
+    fn visit_on_angle_bisector(&mut self, _constraint: &OnAngleBisectorConstraint) {}
+
+    /// Visit a point side-of-line constraint
+    fn visit_point_side_of_line(&mut self, _constraint: &PointSideOfLineConstraint) {}
+
+    /// Visit a circle radius constraint
+    fn visit_circle_radius(&mut self, _constraint: &CircleRadiusConstraint) {}
+
+    /// Visit a point-on-circle-at-angle constraint
+    fn visit_point_on_circle_at_angle(&mut self, _constraint: &PointOnCircleAtAngleConstraint) {}
+
+    /// Visit a radius-equals-line-length constraint
+    fn visit_radius_equals_line_length(&mut self, _constraint: &RadiusEqualsLineLengthConstraint) {}
+
+    /// Visit an equal radius constraint
+    fn visit_all_equal_radius(&mut self, _constraint: &AllEqualRadiusConstraint) {}
+
+    /// Visit a bolt circle constraint
+    fn visit_bolt_circle(&mut self, _constraint: &BoltCircleConstraint) {}
+
+    /// Visit a point-at-circle-intersection constraint
+    fn visit_point_at_circle_intersection(
+        &mut self,
+        _constraint: &PointAtCircleIntersectionConstraint,
+    ) {
+    }
+
+    /// Visit a circle non-overlap constraint
+    fn visit_circle_non_overlap(&mut self, _constraint: &CircleNonOverlapConstraint) {}
+
+    /// Visit a circumference-fraction line length constraint
+    fn visit_line_length_from_circumference(
+        &mut self,
+        _constraint: &LineLengthFromCircumferenceConstraint,
+    ) {
+    }
+
+    /// Visit a chord length constraint
+    fn visit_chord_length(&mut self, _constraint: &ChordLengthConstraint) {}
+
+    /// Visit a chord-on-line constraint
+    fn visit_chord_on_line(&mut self, _constraint: &ChordOnLineConstraint) {}
+
+    /// Visit a diameter constraint
+    fn visit_diameter(&mut self, _constraint: &DiameterConstraint) {}
+
+    /// Visit a concyclic points constraint
+    fn visit_concyclic(&mut self, _constraint: &ConcyclicConstraint) {}
+
+    /// Visit a minimum area constraint
+    fn visit_min_area(&mut self, _constraint: &MinAreaConstraint) {}
+
+    /// Visit a convex polygon constraint
+    fn visit_convex_polygon(&mut self, _constraint: &ConvexPolygonConstraint) {}
+
+    /// Visit a closed loop constraint
+    fn visit_closed_loop(&mut self, _constraint: &ClosedLoopConstraint) {}
+
+    /// Visit a parallelogram constraint
+    fn visit_parallelogram(&mut self, _constraint: &ParallelogramConstraint) {}
+
+    /// Visit an equal diagonals constraint
+    fn visit_equal_diagonals(&mut self, _constraint: &EqualDiagonalsConstraint) {}
+
+    /// Visit a rhombus constraint
+    fn visit_rhombus(&mut self, _constraint: &RhombusConstraint) {}
+
+    /// Visit a perimeter constraint
+    fn visit_perimeter(&mut self, _constraint: &PerimeterConstraint) {}
+
+    /// Visit a triangle area constraint
+    fn visit_triangle_area(&mut self, _constraint: &TriangleAreaConstraint) {}
+
+    /// Visit a tangent line-arc join constraint
+    fn visit_tangent_join(&mut self, _constraint: &TangentJoinConstraint) {}
+
+    /// Visit an arc sweep constraint
+    fn visit_arc_sweep(&mut self, _constraint: &ArcSweepConstraint) {}
+
+    /// Visit an arc tangent constraint
+    fn visit_arc_tangent(&mut self, _constraint: &ArcTangentConstraint) {}
+
+    /// Visit an ellipse aspect ratio constraint
+    fn visit_ellipse_aspect_ratio(&mut self, _constraint: &EllipseAspectRatioConstraint) {}
+
+    /// Visit an ellipse semi-axes constraint
+    fn visit_ellipse_semi_axes(&mut self, _constraint: &EllipseSemiAxesConstraint) {}
+
+    /// Visit a point symmetry constraint
+    fn visit_symmetry(&mut self, _constraint: &SymmetryConstraint) {}
+
+    /// Visit a line symmetry constraint
+    fn visit_line_symmetry(&mut self, _constraint: &LineSymmetryConstraint) {}
+
+    /// Visit a radial symmetry constraint
+    fn visit_radial_symmetry(&mut self, _constraint: &RadialSymmetryConstraint) {}
+
+    /// Visit a constraint type not known to this trait (e.g. user-defined)
+    fn visit_other(&mut self, _constraint: &dyn Constraint) {}
 }
 
 /// Trait for querying sketch state during constraint application.
@@ -36,6 +354,42 @@ pub trait SketchQuery {
     /// Get the center PointId and radius Real variable for a circle
     fn circle_center_and_radius(&self, circle_id: CircleId) -> Result<(PointId, Real<'_>)>;
 
+    /// Get the supporting CircleId and start/end PointIds for an arc
+    ///
+    /// Defaults to an error so sketches without arc support need not
+    /// implement it.
+    fn arc_circle_and_endpoints(&self, arc_id: ArcId) -> Result<(CircleId, PointId, PointId)> {
+        Err(TextCadError::EntityError(format!(
+            "Arc {:?} not found",
+            arc_id
+        )))
+    }
+
+    /// Get the (start, end) angle variables for an arc
+    ///
+    /// Defaults to an error so sketches without arc support need not
+    /// implement it.
+    fn arc_angle_variables(&self, arc_id: ArcId) -> Result<(Real<'_>, Real<'_>)> {
+        Err(TextCadError::EntityError(format!(
+            "Arc {:?} not found",
+            arc_id
+        )))
+    }
+
+    /// Get the center PointId and (semi-major, semi-minor) variables for an ellipse
+    ///
+    /// Defaults to an error so sketches without ellipse support need not
+    /// implement it.
+    fn ellipse_center_and_axes(
+        &self,
+        ellipse_id: EllipseId,
+    ) -> Result<(PointId, Real<'_>, Real<'_>)> {
+        Err(TextCadError::EntityError(format!(
+            "Ellipse {:?} not found",
+            ellipse_id
+        )))
+    }
+
     /// Get the Z3 Real variable for a length/distance value
     fn length_variable(&self, name: &str) -> Result<Real<'_>>;
 
@@ -0,0 +1,252 @@
+//! Arc-related constraints for geometric modeling
+//!
+//! Implements constraints that relate Arc entities to lines and to their own
+//! angle variables.
+
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::constraints::real_from_f64;
+use crate::entities::PointId;
+use crate::entity::{ArcId, LineId};
+use crate::error::{Result, TextCadError};
+use crate::units::Angle;
+use std::ops::{Add, Mul, Sub};
+use z3::ast::{Ast, Bool, Real};
+
+/// Constraint that joins a line to an arc with tangent continuity
+///
+/// Asserts that `join` coincides with an endpoint of the line and an endpoint
+/// of the arc, lies on the arc's circle (`|j - c|² = r²`), and that the line
+/// direction is perpendicular to the radius there (`(end - start) · (j - c) = 0`).
+#[derive(Debug, Clone)]
+pub struct TangentJoinConstraint {
+    /// Line meeting the arc
+    pub line: LineId,
+    /// Arc meeting the line
+    pub arc: ArcId,
+    /// Shared endpoint of the line and the arc
+    pub join: PointId,
+}
+
+impl TangentJoinConstraint {
+    /// Create a new tangent join constraint
+    pub fn new(line: LineId, arc: ArcId, join: PointId) -> Self {
+        Self { line, arc, join }
+    }
+}
+
+impl Constraint for TangentJoinConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (line_start, line_end) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (circle, arc_start, arc_end) = sketch
+            .arc_circle_and_endpoints(self.arc)
+            .map_err(|_| TextCadError::EntityError(format!("Arc {:?} not found", self.arc)))?;
+        let (center, radius) = sketch.circle_center_and_radius(circle).map_err(|_| {
+            TextCadError::EntityError(format!(
+                "Circle {:?} of arc {:?} not found",
+                circle, self.arc
+            ))
+        })?;
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (jx, jy) = point(self.join)?;
+        let (cx, cy) = point(center)?;
+        let (x1, y1) = point(line_start)?;
+        let (x2, y2) = point(line_end)?;
+        let (ax1, ay1) = point(arc_start)?;
+        let (ax2, ay2) = point(arc_end)?;
+
+        let at = |x, y| Bool::and(context, &[&jx._eq(x), &jy._eq(y)]);
+
+        // The join is an endpoint of both the line and the arc
+        solver.assert(&Bool::or(context, &[&at(&x1, &y1), &at(&x2, &y2)]));
+        solver.assert(&Bool::or(context, &[&at(&ax1, &ay1), &at(&ax2, &ay2)]));
+
+        // |j - c|² = r²
+        let rx = (&jx).sub(&cx);
+        let ry = (&jy).sub(&cy);
+        let dist_sq = (&rx).mul(&rx).add(&(&ry).mul(&ry));
+        solver.assert(&dist_sq._eq(&(&radius).mul(&radius)));
+
+        // (end - start) · (j - c) = 0
+        let dot = (&x2).sub(&x1).mul(&rx).add(&(&y2).sub(&y1).mul(&ry));
+        solver.assert(&dot._eq(&Real::from_real(context, 0, 1)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} joins arc {:?} tangentially at point {:?}",
+            self.line, self.arc, self.join
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_tangent_join(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Line(self.line),
+            EntityRef::Arc(self.arc),
+            EntityRef::Point(self.join),
+        ]
+    }
+}
+
+/// Constraint that fixes how much of its circle an arc covers
+///
+/// Asserts `end_angle - start_angle = sweep` on the arc's angle variables.
+#[derive(Debug, Clone)]
+pub struct ArcSweepConstraint {
+    /// Arc to constrain
+    pub arc: ArcId,
+    /// Counter-clockwise sweep from start to end
+    pub sweep: Angle,
+}
+
+impl ArcSweepConstraint {
+    /// Create a new arc sweep constraint
+    pub fn new(arc: ArcId, sweep: Angle) -> Self {
+        Self { arc, sweep }
+    }
+}
+
+impl Constraint for ArcSweepConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (start_angle, end_angle) = sketch
+            .arc_angle_variables(self.arc)
+            .map_err(|_| TextCadError::EntityError(format!("Arc {:?} not found", self.arc)))?;
+
+        // end - start = sweep
+        let sweep = real_from_f64(context, self.sweep.to_radians())?;
+        solver.assert(&(&end_angle).sub(&start_angle)._eq(&sweep));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Arc {:?} sweeps {:.1}°", self.arc, self.sweep.to_degrees())
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_arc_sweep(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Arc(self.arc)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that fixes the direction of travel where an arc starts or ends
+///
+/// The arc runs counter-clockwise, so with radius vector `r = p - c` at the
+/// chosen endpoint `p`, the tangent direction is `(-r_y, r_x)`. Against the
+/// unit direction `u = (cos θ, sin θ)` this asserts the radius is
+/// perpendicular to `u` (`r · u = 0`) and the tangent points along `u`
+/// rather than against it (`r × u > 0`). Both are linear in the coordinates.
+#[derive(Debug, Clone)]
+pub struct ArcTangentConstraint {
+    /// Arc to constrain
+    pub arc: ArcId,
+    /// Constrain the tangent at the start point if true, else at the end point
+    pub at_start: bool,
+    /// Direction of travel, measured counter-clockwise from the positive x-axis
+    pub angle: Angle,
+}
+
+impl ArcTangentConstraint {
+    /// Create a new arc tangent constraint
+    pub fn new(arc: ArcId, at_start: bool, angle: Angle) -> Self {
+        Self {
+            arc,
+            at_start,
+            angle,
+        }
+    }
+}
+
+impl Constraint for ArcTangentConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (circle, arc_start, arc_end) = sketch
+            .arc_circle_and_endpoints(self.arc)
+            .map_err(|_| TextCadError::EntityError(format!("Arc {:?} not found", self.arc)))?;
+        let (center, _) = sketch.circle_center_and_radius(circle).map_err(|_| {
+            TextCadError::EntityError(format!(
+                "Circle {:?} of arc {:?} not found",
+                circle, self.arc
+            ))
+        })?;
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let endpoint = if self.at_start { arc_start } else { arc_end };
+        let (px, py) = point(endpoint)?;
+        let (cx, cy) = point(center)?;
+
+        let rx = (&px).sub(&cx);
+        let ry = (&py).sub(&cy);
+        let cos = real_from_f64(context, self.angle.cos())?;
+        let sin = real_from_f64(context, self.angle.sin())?;
+        let zero = Real::from_real(context, 0, 1);
+
+        // r · u = 0
+        let dot = (&rx).mul(&cos).add(&(&ry).mul(&sin));
+        solver.assert(&dot._eq(&zero));
+
+        // r × u > 0, i.e. (-r_y, r_x) · u > 0
+        let cross = (&rx).mul(&sin).sub(&(&ry).mul(&cos));
+        solver.assert(&cross.gt(&zero));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Arc {:?} has tangent {:.1}° at its {}",
+            self.arc,
+            self.angle.to_degrees(),
+            if self.at_start { "start" } else { "end" }
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_arc_tangent(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Arc(self.arc)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
@@ -2,12 +2,16 @@
 //!
 //! Implements fundamental constraints for point positioning and coincidence.
 
-use crate::constraint::{Constraint, SketchQuery};
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::constraints::real_from_f64;
 use crate::entities::PointId;
+use crate::entity::LineId;
 // Note: CircleId import will be needed when circle constraints are implemented
 // use crate::entity::CircleId;
 use crate::error::{Result, TextCadError};
+use crate::sketch::Coord;
 use crate::units::Length;
+use std::ops::{Add, Mul, Sub};
 use z3::ast::{Ast, Real};
 
 /// Constraint that makes two points coincident (same coordinates)
@@ -54,6 +58,18 @@ impl Constraint for CoincidentPointsConstraint {
             self.point1, self.point2
         )
     }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_coincident(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Point(self.point1), EntityRef::Point(self.point2)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
 }
 
 /// Constraint that fixes a point at specific coordinates
@@ -110,6 +126,543 @@ impl Constraint for FixedPositionConstraint {
             self.y.to_meters()
         )
     }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_fixed(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Point(self.point)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that fixes the signed horizontal distance between two points
+///
+/// Asserts `x2 - x1 = distance`, leaving the y coordinates free.
+#[derive(Debug, Clone)]
+pub struct HorizontalDistanceConstraint {
+    /// Reference point
+    pub point1: PointId,
+    /// Point offset horizontally from the reference
+    pub point2: PointId,
+    /// Signed horizontal distance from point1 to point2
+    pub distance: Length,
+}
+
+impl HorizontalDistanceConstraint {
+    /// Create a new horizontal distance constraint
+    pub fn new(point1: PointId, point2: PointId, distance: Length) -> Self {
+        Self {
+            point1,
+            point2,
+            distance,
+        }
+    }
+}
+
+impl Constraint for HorizontalDistanceConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (x1, _) = sketch
+            .point_variables(self.point1)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point1)))?;
+        let (x2, _) = sketch
+            .point_variables(self.point2)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point2)))?;
+
+        let target = real_from_f64(context, self.distance.to_meters())?;
+
+        // x2 - x1 = distance
+        solver.assert(&(&x2).sub(&x1)._eq(&target));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is {:.3}m horizontally from point {:?}",
+            self.point2,
+            self.distance.to_meters(),
+            self.point1
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_horizontal_distance(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Point(self.point1), EntityRef::Point(self.point2)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that fixes the signed vertical distance between two points
+///
+/// Asserts `y2 - y1 = distance`, leaving the x coordinates free.
+#[derive(Debug, Clone)]
+pub struct VerticalDistanceConstraint {
+    /// Reference point
+    pub point1: PointId,
+    /// Point offset vertically from the reference
+    pub point2: PointId,
+    /// Signed vertical distance from point1 to point2
+    pub distance: Length,
+}
+
+impl VerticalDistanceConstraint {
+    /// Create a new vertical distance constraint
+    pub fn new(point1: PointId, point2: PointId, distance: Length) -> Self {
+        Self {
+            point1,
+            point2,
+            distance,
+        }
+    }
+}
+
+impl Constraint for VerticalDistanceConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (_, y1) = sketch
+            .point_variables(self.point1)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point1)))?;
+        let (_, y2) = sketch
+            .point_variables(self.point2)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point2)))?;
+
+        let target = real_from_f64(context, self.distance.to_meters())?;
+
+        // y2 - y1 = distance
+        solver.assert(&(&y2).sub(&y1)._eq(&target));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is {:.3}m vertically from point {:?}",
+            self.point2,
+            self.distance.to_meters(),
+            self.point1
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_vertical_distance(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Point(self.point1), EntityRef::Point(self.point2)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that two points share one offset component from a datum
+///
+/// For `Coord::X` asserts `xa - datum_x = xb - datum_x` (and likewise for
+/// `Coord::Y`), aligning both points on the same grid column or row relative
+/// to the datum while leaving the other coordinate free.
+#[derive(Debug, Clone)]
+pub struct EqualOffsetConstraint {
+    /// Reference point the offsets are measured from
+    pub datum: PointId,
+    /// First point
+    pub a: PointId,
+    /// Second point
+    pub b: PointId,
+    /// Offset component that must match
+    pub coord: Coord,
+}
+
+impl EqualOffsetConstraint {
+    /// Create a new equal offset constraint
+    pub fn new(datum: PointId, a: PointId, b: PointId, coord: Coord) -> Self {
+        Self { datum, a, b, coord }
+    }
+}
+
+impl Constraint for EqualOffsetConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let component = |point: PointId| {
+            let (x, y) = sketch
+                .point_variables(point)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", point)))?;
+            Ok::<_, TextCadError>(match self.coord {
+                Coord::X => x,
+                Coord::Y => y,
+            })
+        };
+        let datum = component(self.datum)?;
+        let a = component(self.a)?;
+        let b = component(self.b)?;
+
+        // a - datum = b - datum
+        solver.assert(&(&a).sub(&datum)._eq(&(&b).sub(&datum)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Points {:?} and {:?} share the same {:?} offset from point {:?}",
+            self.a, self.b, self.coord, self.datum
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_equal_offset(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Point(self.datum),
+            EntityRef::Point(self.a),
+            EntityRef::Point(self.b),
+        ]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that keeps a satellite point at a fixed distance from a center point
+///
+/// Asserts `(sx - cx)² + (sy - cy)² = radius²`. Both points may move: the
+/// satellite follows the center around, wherever other constraints put it.
+#[derive(Debug, Clone)]
+pub struct OrbitConstraint {
+    /// Point the satellite orbits
+    pub center: PointId,
+    /// Point kept on the orbit
+    pub satellite: PointId,
+    /// Orbit radius
+    pub radius: Length,
+}
+
+impl OrbitConstraint {
+    /// Create a new orbit constraint
+    pub fn new(center: PointId, satellite: PointId, radius: Length) -> Self {
+        Self {
+            center,
+            satellite,
+            radius,
+        }
+    }
+}
+
+impl Constraint for OrbitConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.radius.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Orbit radius cannot be negative, got {:.3}m",
+                self.radius.to_meters()
+            )));
+        }
+
+        let (cx, cy) = sketch.point_variables(self.center).map_err(|_| {
+            TextCadError::EntityError(format!("Center point {:?} not found", self.center))
+        })?;
+        let (sx, sy) = sketch.point_variables(self.satellite).map_err(|_| {
+            TextCadError::EntityError(format!("Satellite point {:?} not found", self.satellite))
+        })?;
+
+        let dx = (&sx).sub(&cx);
+        let dy = (&sy).sub(&cy);
+        let dist_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
+
+        // |s - c|² = radius²
+        let radius = real_from_f64(context, self.radius.to_meters())?;
+        solver.assert(&dist_sq._eq(&(&radius).mul(&radius)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} orbits point {:?} at {:.3}m",
+            self.satellite,
+            self.center,
+            self.radius.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_orbit(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Point(self.center),
+            EntityRef::Point(self.satellite),
+        ]
+    }
+}
+
+/// Constraint that places a point at the circumcenter of a triangle
+///
+/// Asserts `|center - a|² = |center - b|²` and `|center - a|² = |center - c|²`.
+/// The squared terms in the center cancel, so for fixed vertices both
+/// equalities are linear in the center's coordinates. Collinear vertices
+/// have no circumcenter and make the sketch unsatisfiable.
+#[derive(Debug, Clone)]
+pub struct CircumcenterConstraint {
+    /// Point placed at the circumcenter
+    pub center: PointId,
+    /// First triangle vertex
+    pub a: PointId,
+    /// Second triangle vertex
+    pub b: PointId,
+    /// Third triangle vertex
+    pub c: PointId,
+}
+
+impl CircumcenterConstraint {
+    /// Create a new circumcenter constraint
+    pub fn new(center: PointId, a: PointId, b: PointId, c: PointId) -> Self {
+        Self { center, a, b, c }
+    }
+}
+
+impl Constraint for CircumcenterConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (ox, oy) = point(self.center)?;
+        let dist_sq = |id: PointId| {
+            let (x, y) = point(id)?;
+            let dx = (&x).sub(&ox);
+            let dy = (&y).sub(&oy);
+            Ok::<_, TextCadError>((&dx).mul(&dx).add(&(&dy).mul(&dy)))
+        };
+
+        let to_a = dist_sq(self.a)?;
+        solver.assert(&to_a._eq(&dist_sq(self.b)?));
+        solver.assert(&to_a._eq(&dist_sq(self.c)?));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is the circumcenter of {:?}, {:?}, {:?}",
+            self.center, self.a, self.b, self.c
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_circumcenter(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Point(self.center),
+            EntityRef::Point(self.a),
+            EntityRef::Point(self.b),
+            EntityRef::Point(self.c),
+        ]
+    }
+}
+
+/// Constraint that pins the centroid of a set of points
+///
+/// Asserts `Σ x_i = n·x` and `Σ y_i = n·y`, which keeps the average position
+/// of the `n` points at `(x, y)` while leaving each point free to move.
+#[derive(Debug, Clone)]
+pub struct FixedCentroidConstraint {
+    /// Points whose average position is fixed
+    pub points: Vec<PointId>,
+    /// Target x coordinate of the centroid
+    pub x: Length,
+    /// Target y coordinate of the centroid
+    pub y: Length,
+}
+
+impl FixedCentroidConstraint {
+    /// Create a new fixed centroid constraint
+    pub fn new(points: Vec<PointId>, x: Length, y: Length) -> Self {
+        Self { points, x, y }
+    }
+}
+
+impl Constraint for FixedCentroidConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.points.is_empty() {
+            return Err(TextCadError::InvalidConstraint(
+                "Centroid requires at least one point".to_string(),
+            ));
+        }
+
+        let (xs, ys): (Vec<_>, Vec<_>) = self
+            .points
+            .iter()
+            .map(|&id| {
+                sketch
+                    .point_variables(id)
+                    .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let n = self.points.len() as f64;
+        let target_x = real_from_f64(context, n * self.x.to_meters())?;
+        let target_y = real_from_f64(context, n * self.y.to_meters())?;
+
+        // Σ x_i = n·x, Σ y_i = n·y
+        let x_refs: Vec<_> = xs.iter().collect();
+        let y_refs: Vec<_> = ys.iter().collect();
+        solver.assert(&Real::add(context, &x_refs)._eq(&target_x));
+        solver.assert(&Real::add(context, &y_refs)._eq(&target_y));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Centroid of {} points is at ({:.3}, {:.3})",
+            self.points.len(),
+            self.x.to_meters(),
+            self.y.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_fixed_centroid(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.points.iter().copied().map(EntityRef::Point).collect()
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Side of a directed line, looking from its start towards its end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Counter-clockwise side (above a line pointing in +x)
+    Left,
+    /// Clockwise side (below a line pointing in +x)
+    Right,
+}
+
+/// Constraint that keeps a point on one side of a line (half-plane)
+///
+/// Uses the sign of the cross product `(x2-x1)*(py-y1) - (y2-y1)*(px-x1)`:
+/// non-negative for `Side::Left`, non-positive for `Side::Right`. Points on
+/// the line itself satisfy either side.
+#[derive(Debug, Clone)]
+pub struct PointSideOfLineConstraint {
+    /// Line defining the half-plane boundary
+    pub line: LineId,
+    /// Point to keep on one side
+    pub point: PointId,
+    /// Side of the line the point must lie on
+    pub side: Side,
+}
+
+impl PointSideOfLineConstraint {
+    /// Create a new point side-of-line constraint
+    pub fn new(line: LineId, point: PointId, side: Side) -> Self {
+        Self { line, point, side }
+    }
+}
+
+impl Constraint for PointSideOfLineConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+
+        let (x1, y1) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Start point {:?} not found", start_id))
+        })?;
+        let (x2, y2) = sketch
+            .point_variables(end_id)
+            .map_err(|_| TextCadError::EntityError(format!("End point {:?} not found", end_id)))?;
+        let (px, py) = sketch
+            .point_variables(self.point)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point)))?;
+
+        // cross = (x2-x1)*(py-y1) - (y2-y1)*(px-x1)
+        let cross = (&x2)
+            .sub(&x1)
+            .mul(&(&py).sub(&y1))
+            .sub(&(&y2).sub(&y1).mul(&(&px).sub(&x1)));
+
+        let zero = Real::from_real(context, 0, 1);
+        match self.side {
+            Side::Left => solver.assert(&cross.ge(&zero)),
+            Side::Right => solver.assert(&cross.le(&zero)),
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} lies on the {:?} side of line {:?}",
+            self.point, self.side, self.line
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_point_side_of_line(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Point(self.point)]
+    }
 }
 
 #[cfg(test)]
@@ -119,7 +672,7 @@ mod tests {
     use generational_arena::Index;
     use std::collections::HashMap;
     use z3::ast::Real;
-    use z3::{Config, Context, Solver};
+    use z3::{Config, Context, SatResult, Solver};
 
     // Mock implementation of SketchQuery for testing
     struct MockSketch<'ctx> {
@@ -273,4 +826,316 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result, Err(TextCadError::EntityError(_))));
     }
+
+    #[test]
+    fn test_horizontal_distance_constraint_creation() {
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+
+        let constraint = HorizontalDistanceConstraint::new(p1, p2, Length::meters(3.0));
+
+        assert_eq!(constraint.point1, p1);
+        assert_eq!(constraint.point2, p2);
+        assert!(constraint.description().contains("3.000m horizontally"));
+    }
+
+    #[test]
+    fn test_horizontal_distance_constraint_solves() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+        let x2 = Real::new_const(&ctx, "x2");
+        let y2 = Real::new_const(&ctx, "y2");
+
+        let mut mock_sketch = MockSketch::new();
+        mock_sketch.add_point(p1, Real::new_const(&ctx, "x1"), Real::new_const(&ctx, "y1"));
+        mock_sketch.add_point(p2, x2.clone(), y2.clone());
+
+        FixedPositionConstraint::new(p1, Length::meters(1.0), Length::meters(2.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        HorizontalDistanceConstraint::new(p1, p2, Length::meters(3.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+
+        // Pin y2 away from y1 to show the vertical coordinate is unconstrained
+        solver.assert(&y2._eq(&Real::from_real(&ctx, 7, 1)));
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let (num, den) = model.eval(&x2, true).unwrap().as_real().unwrap();
+        assert!((num as f64 / den as f64 - 4.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_vertical_distance_constraint_solves() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+        let x2 = Real::new_const(&ctx, "x2");
+        let y2 = Real::new_const(&ctx, "y2");
+
+        let mut mock_sketch = MockSketch::new();
+        mock_sketch.add_point(p1, Real::new_const(&ctx, "x1"), Real::new_const(&ctx, "y1"));
+        mock_sketch.add_point(p2, x2.clone(), y2.clone());
+
+        FixedPositionConstraint::new(p1, Length::meters(1.0), Length::meters(2.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        VerticalDistanceConstraint::new(p1, p2, Length::meters(-3.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+
+        // Pin x2 away from x1 to show the horizontal coordinate is unconstrained
+        solver.assert(&x2._eq(&Real::from_real(&ctx, 5, 1)));
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let (num, den) = model.eval(&y2, true).unwrap().as_real().unwrap();
+        assert!((num as f64 / den as f64 + 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_axis_distances_beyond_i32_fixed_point_range() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+        let x1 = Real::new_const(&ctx, "x1");
+        let y1 = Real::new_const(&ctx, "y1");
+        let x2 = Real::new_const(&ctx, "x2");
+        let y2 = Real::new_const(&ctx, "y2");
+
+        let mut mock_sketch = MockSketch::new();
+        mock_sketch.add_point(p1, x1.clone(), y1.clone());
+        mock_sketch.add_point(p2, x2.clone(), y2.clone());
+
+        // 5000 m scaled by 1e6 would saturate an i32
+        HorizontalDistanceConstraint::new(p1, p2, Length::meters(5000.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        VerticalDistanceConstraint::new(p1, p2, Length::meters(-2500.5))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let (num, den) = model
+            .eval(&(&x2).sub(&x1), true)
+            .unwrap()
+            .as_real()
+            .unwrap();
+        assert!((num as f64 / den as f64 - 5000.0).abs() < 1e-9);
+        let (num, den) = model
+            .eval(&(&y2).sub(&y1), true)
+            .unwrap()
+            .as_real()
+            .unwrap();
+        assert!((num as f64 / den as f64 + 2500.5).abs() < 1e-9);
+
+        let nan = HorizontalDistanceConstraint::new(p1, p2, Length::meters(f64::NAN));
+        assert!(matches!(
+            nan.apply(&ctx, &solver, &mock_sketch),
+            Err(TextCadError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_equal_offset_constraint_shares_x_offset() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let datum = PointId(Index::from_raw_parts(0, 0));
+        let a = PointId(Index::from_raw_parts(1, 0));
+        let b = PointId(Index::from_raw_parts(2, 0));
+        let xb = Real::new_const(&ctx, "xb");
+        let yb = Real::new_const(&ctx, "yb");
+
+        let mut mock_sketch = MockSketch::new();
+        mock_sketch.add_point(
+            datum,
+            Real::new_const(&ctx, "xd"),
+            Real::new_const(&ctx, "yd"),
+        );
+        mock_sketch.add_point(a, Real::new_const(&ctx, "xa"), Real::new_const(&ctx, "ya"));
+        mock_sketch.add_point(b, xb.clone(), yb.clone());
+
+        FixedPositionConstraint::new(datum, Length::meters(1.0), Length::meters(1.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        FixedPositionConstraint::new(a, Length::meters(3.0), Length::meters(0.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        EqualOffsetConstraint::new(datum, a, b, Coord::X)
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+
+        // The y offset stays free
+        solver.assert(&yb._eq(&Real::from_real(&ctx, 7, 1)));
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let (num, den) = model.eval(&xb, true).unwrap().as_real().unwrap();
+        assert!((num as f64 / den as f64 - 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_orbit_constraint_follows_moving_center() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let anchor = PointId(Index::from_raw_parts(0, 0));
+        let center = PointId(Index::from_raw_parts(1, 0));
+        let satellite = PointId(Index::from_raw_parts(2, 0));
+        let (cx, cy) = (Real::new_const(&ctx, "cx"), Real::new_const(&ctx, "cy"));
+        let (sx, sy) = (Real::new_const(&ctx, "sx"), Real::new_const(&ctx, "sy"));
+
+        let mut mock_sketch = MockSketch::new();
+        mock_sketch.add_point(
+            anchor,
+            Real::new_const(&ctx, "ax"),
+            Real::new_const(&ctx, "ay"),
+        );
+        mock_sketch.add_point(center, cx.clone(), cy.clone());
+        mock_sketch.add_point(satellite, sx.clone(), sy.clone());
+
+        // The center is pulled to (3, 1) by its offsets from a fixed anchor
+        FixedPositionConstraint::new(anchor, Length::meters(0.0), Length::meters(0.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        HorizontalDistanceConstraint::new(anchor, center, Length::meters(3.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        VerticalDistanceConstraint::new(anchor, center, Length::meters(1.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+        OrbitConstraint::new(center, satellite, Length::meters(2.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let value = |v: &Real| {
+            let (num, den) = model.eval(v, true).unwrap().as_real().unwrap();
+            num as f64 / den as f64
+        };
+        let distance =
+            ((value(&sx) - value(&cx)).powi(2) + (value(&sy) - value(&cy)).powi(2)).sqrt();
+        assert!((value(&cx) - 3.0).abs() < 1e-10);
+        assert!((distance - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_circumcenter_of_right_triangle() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let ids: Vec<PointId> = (0..4)
+            .map(|i| PointId(Index::from_raw_parts(i, 0)))
+            .collect();
+        let (ox, oy) = (Real::new_const(&ctx, "ox"), Real::new_const(&ctx, "oy"));
+
+        let mut mock_sketch = MockSketch::new();
+        mock_sketch.add_point(ids[0], ox.clone(), oy.clone());
+        for (i, &id) in ids.iter().enumerate().skip(1) {
+            mock_sketch.add_point(
+                id,
+                Real::new_const(&ctx, format!("x{}", i)),
+                Real::new_const(&ctx, format!("y{}", i)),
+            );
+        }
+
+        // Right triangle: the circumcenter is the hypotenuse midpoint (2, 1)
+        for (&id, (x, y)) in ids[1..].iter().zip([(0.0, 0.0), (4.0, 0.0), (0.0, 2.0)]) {
+            FixedPositionConstraint::new(id, Length::meters(x), Length::meters(y))
+                .apply(&ctx, &solver, &mock_sketch)
+                .unwrap();
+        }
+        CircumcenterConstraint::new(ids[0], ids[1], ids[2], ids[3])
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let value = |v: &Real| {
+            let (num, den) = model.eval(v, true).unwrap().as_real().unwrap();
+            num as f64 / den as f64
+        };
+        assert!((value(&ox) - 2.0).abs() < 1e-10);
+        assert!((value(&oy) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_fixed_centroid_of_square() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let ids: Vec<PointId> = (0..4)
+            .map(|i| PointId(Index::from_raw_parts(i, 0)))
+            .collect();
+        let vars: Vec<(Real, Real)> = (0..4)
+            .map(|i| {
+                (
+                    Real::new_const(&ctx, format!("x{}", i)),
+                    Real::new_const(&ctx, format!("y{}", i)),
+                )
+            })
+            .collect();
+
+        let mut mock_sketch = MockSketch::new();
+        for (&id, (x, y)) in ids.iter().zip(&vars) {
+            mock_sketch.add_point(id, x.clone(), y.clone());
+        }
+
+        // A 2m square, free to translate, with corners offset from the first
+        for (i, (dx, dy)) in [(1, (2.0, 0.0)), (2, (2.0, 2.0)), (3, (0.0, 2.0))] {
+            HorizontalDistanceConstraint::new(ids[0], ids[i], Length::meters(dx))
+                .apply(&ctx, &solver, &mock_sketch)
+                .unwrap();
+            VerticalDistanceConstraint::new(ids[0], ids[i], Length::meters(dy))
+                .apply(&ctx, &solver, &mock_sketch)
+                .unwrap();
+        }
+        FixedCentroidConstraint::new(ids.clone(), Length::meters(2.0), Length::meters(2.0))
+            .apply(&ctx, &solver, &mock_sketch)
+            .unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let value = |v: &Real| {
+            let (num, den) = model.eval(v, true).unwrap().as_real().unwrap();
+            num as f64 / den as f64
+        };
+        let mean_x = vars.iter().map(|(x, _)| value(x)).sum::<f64>() / 4.0;
+        let mean_y = vars.iter().map(|(_, y)| value(y)).sum::<f64>() / 4.0;
+        assert!((mean_x - 2.0).abs() < 1e-10);
+        assert!((mean_y - 2.0).abs() < 1e-10);
+        assert!((value(&vars[0].0) - 1.0).abs() < 1e-10);
+        assert!((value(&vars[0].1) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_point_side_of_line_constraint_creation() {
+        let line = LineId(Index::from_raw_parts(0, 0));
+        let p = PointId(Index::from_raw_parts(2, 0));
+
+        let constraint = PointSideOfLineConstraint::new(line, p, Side::Left);
+
+        assert_eq!(constraint.line, line);
+        assert_eq!(constraint.point, p);
+        assert_eq!(constraint.side, Side::Left);
+        assert!(constraint.description().contains("Left side"));
+    }
 }
@@ -0,0 +1,1344 @@
+//! Circle-related constraints for geometric modeling
+//!
+//! Implements constraints that relate points to Circle entities or to implicit circles.
+
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::constraints::line::line_length_variable;
+use crate::constraints::real_from_f64;
+use crate::entities::PointId;
+use crate::entity::{CircleId, LineId};
+use crate::error::{Result, TextCadError};
+use crate::units::{Angle, Length, Scalar};
+use std::ops::{Add, Mul, Sub};
+use z3::ast::{Ast, Real};
+
+/// Constraint that sets the radius of a circle to a specific value
+///
+/// Asserts `r = radius` on the circle's radius variable, using the length's
+/// exact rational when it carries one.
+#[derive(Debug, Clone)]
+pub struct CircleRadiusConstraint {
+    /// Circle to constrain
+    pub circle: CircleId,
+    /// Target radius
+    pub radius: Length,
+}
+
+impl CircleRadiusConstraint {
+    /// Create a new circle radius constraint
+    pub fn new(circle: CircleId, radius: Length) -> Self {
+        Self { circle, radius }
+    }
+}
+
+impl Constraint for CircleRadiusConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.radius.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Circle {:?} cannot have negative radius {:.3}m",
+                self.circle,
+                self.radius.to_meters()
+            )));
+        }
+
+        let (_, radius) = sketch.circle_center_and_radius(self.circle).map_err(|_| {
+            TextCadError::EntityError(format!("Circle {:?} not found", self.circle))
+        })?;
+
+        let target = match self.radius.exact_rational() {
+            Some((num, den)) => Real::from_real_str(context, &num.to_string(), &den.to_string())
+                .ok_or_else(|| {
+                    TextCadError::InvalidConstraint(format!(
+                        "Cannot encode radius {}/{}m for circle {:?}",
+                        num, den, self.circle
+                    ))
+                })?,
+            None => real_from_f64(context, self.radius.to_meters())?,
+        };
+        solver.assert(&radius._eq(&target));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Circle {:?} has radius {:.3}m",
+            self.circle,
+            self.radius.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_circle_radius(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Circle(self.circle)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that places a point on a circle at a specific angle
+///
+/// Asserts `px = cx + r·cos(θ)` and `py = cy + r·sin(θ)`, where `r` is the
+/// circle's radius variable. Since θ is known, cos/sin are encoded as
+/// rationals and the constraint stays polynomial.
+#[derive(Debug, Clone)]
+pub struct PointOnCircleAtAngleConstraint {
+    /// Circle the point lies on
+    pub circle: CircleId,
+    /// Point to place on the circle
+    pub point: PointId,
+    /// Angle measured counter-clockwise from the positive x-axis
+    pub angle: Angle,
+}
+
+impl PointOnCircleAtAngleConstraint {
+    /// Create a new point-on-circle-at-angle constraint
+    pub fn new(circle: CircleId, point: PointId, angle: Angle) -> Self {
+        Self {
+            circle,
+            point,
+            angle,
+        }
+    }
+}
+
+impl Constraint for PointOnCircleAtAngleConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        // Get the circle's center and radius
+        let (center_id, radius) = sketch.circle_center_and_radius(self.circle).map_err(|_| {
+            TextCadError::EntityError(format!("Circle {:?} not found", self.circle))
+        })?;
+
+        // Get the center and point coordinates
+        let (cx, cy) = sketch.point_variables(center_id).map_err(|_| {
+            TextCadError::EntityError(format!("Center point {:?} not found", center_id))
+        })?;
+        let (px, py) = sketch
+            .point_variables(self.point)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point)))?;
+
+        // Convert cos/sin to Z3 rational values (6 decimal places)
+        let cos_val = Real::from_real(context, (self.angle.cos() * 1_000_000.0) as i32, 1_000_000);
+        let sin_val = Real::from_real(context, (self.angle.sin() * 1_000_000.0) as i32, 1_000_000);
+
+        // px = cx + r*cos(θ), py = cy + r*sin(θ)
+        let target_x = (&cx).add(&(&radius).mul(&cos_val));
+        let target_y = (&cy).add(&(&radius).mul(&sin_val));
+
+        solver.assert(&px._eq(&target_x));
+        solver.assert(&py._eq(&target_y));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} lies on circle {:?} at {:.3}°",
+            self.point,
+            self.circle,
+            self.angle.to_degrees()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_point_on_circle_at_angle(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Circle(self.circle), EntityRef::Point(self.point)]
+    }
+}
+
+/// Constraint that places all given points on a common circle
+///
+/// Introduces hidden center `(cx, cy)` and squared radius `r²` variables and
+/// asserts `(px - cx)² + (py - cy)² = r²` for every point, with `r² > 0`.
+/// The circle does not need to exist as a sketch entity.
+#[derive(Debug, Clone)]
+pub struct ConcyclicConstraint {
+    /// Points that must share a circle
+    pub points: Vec<PointId>,
+}
+
+impl ConcyclicConstraint {
+    /// Create a new concyclic constraint
+    pub fn new(points: Vec<PointId>) -> Self {
+        Self { points }
+    }
+}
+
+impl Constraint for ConcyclicConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.points.len() < 3 {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Concyclic constraint requires at least 3 points, got {}",
+                self.points.len()
+            )));
+        }
+
+        // Hidden circle variables, named uniquely from the point IDs
+        let key = self
+            .points
+            .iter()
+            .map(|p| p.0.into_raw_parts().0.to_string())
+            .collect::<Vec<_>>()
+            .join("_");
+        let cx = Real::new_const(context, format!("concyclic_{}_cx", key));
+        let cy = Real::new_const(context, format!("concyclic_{}_cy", key));
+        let r_sq = Real::new_const(context, format!("concyclic_{}_r_sq", key));
+
+        let zero = Real::from_real(context, 0, 1);
+        solver.assert(&r_sq.gt(&zero));
+
+        for &point in &self.points {
+            let (px, py) = sketch
+                .point_variables(point)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", point)))?;
+
+            // (px - cx)² + (py - cy)² = r²
+            let dx = (&px).sub(&cx);
+            let dy = (&py).sub(&cy);
+            let dist_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
+            solver.assert(&dist_sq._eq(&r_sq));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Points {:?} lie on a common circle", self.points)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_concyclic(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.points.iter().copied().map(EntityRef::Point).collect()
+    }
+}
+
+/// Constraint that ties a circle's radius to a multiple of a line's length
+///
+/// Asserts `radius = scale * len(line)`, where the line length is an explicit
+/// length variable (see `line_length_variable`).
+#[derive(Debug, Clone)]
+pub struct RadiusEqualsLineLengthConstraint {
+    /// Circle whose radius is constrained
+    pub circle: CircleId,
+    /// Line whose length drives the radius
+    pub line: LineId,
+    /// Multiplier applied to the line length
+    pub scale: f64,
+}
+
+impl RadiusEqualsLineLengthConstraint {
+    /// Create a new radius-equals-line-length constraint
+    ///
+    /// `scale` accepts either a raw f64 or a `Scalar`.
+    pub fn new(circle: CircleId, line: LineId, scale: impl Into<Scalar>) -> Self {
+        Self {
+            circle,
+            line,
+            scale: scale.into().value(),
+        }
+    }
+}
+
+impl Constraint for RadiusEqualsLineLengthConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if !(self.scale.is_finite() && self.scale >= 0.0) {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Radius scale for circle {:?} must be finite and non-negative, got {}",
+                self.circle, self.scale
+            )));
+        }
+
+        let (_, radius) = sketch.circle_center_and_radius(self.circle).map_err(|_| {
+            TextCadError::EntityError(format!("Circle {:?} not found", self.circle))
+        })?;
+        let len = line_length_variable(context, solver, sketch, self.line)?;
+
+        // radius = scale * len
+        let scale = real_from_f64(context, self.scale)?;
+        solver.assert(&radius._eq(&(&scale).mul(&len)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Circle {:?} has radius {:.3} * length of line {:?}",
+            self.circle, self.scale, self.line
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_radius_equals_line_length(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Circle(self.circle), EntityRef::Line(self.line)]
+    }
+}
+
+/// Constraint that two points on a circle are separated by a chord length
+///
+/// Asserts both points lie on the circle (`|p - c|² = r²`) and that their
+/// mutual distance satisfies `|a - b|² = chord²`.
+#[derive(Debug, Clone)]
+pub struct ChordLengthConstraint {
+    /// Circle both points lie on
+    pub circle: CircleId,
+    /// First point on the circle
+    pub a: PointId,
+    /// Second point on the circle
+    pub b: PointId,
+    /// Straight-line distance between the two points
+    pub chord: Length,
+}
+
+impl ChordLengthConstraint {
+    /// Create a new chord length constraint
+    pub fn new(circle: CircleId, a: PointId, b: PointId, chord: Length) -> Self {
+        Self {
+            circle,
+            a,
+            b,
+            chord,
+        }
+    }
+}
+
+impl Constraint for ChordLengthConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.chord.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Chord of circle {:?} cannot have negative length {:.3}m",
+                self.circle,
+                self.chord.to_meters()
+            )));
+        }
+        let chord = self.chord.to_meters();
+
+        let (center, radius) = sketch.circle_center_and_radius(self.circle).map_err(|_| {
+            TextCadError::EntityError(format!("Circle {:?} not found", self.circle))
+        })?;
+        let (cx, cy) = sketch.point_variables(center).map_err(|_| {
+            TextCadError::EntityError(format!("Circle center {:?} not found", center))
+        })?;
+        let (ax, ay) = sketch
+            .point_variables(self.a)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.a)))?;
+        let (bx, by) = sketch
+            .point_variables(self.b)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.b)))?;
+
+        // Both points on the circle
+        let r_sq = (&radius).mul(&radius);
+        solver.assert(&distance_squared((&ax, &ay), (&cx, &cy))._eq(&r_sq));
+        solver.assert(&distance_squared((&bx, &by), (&cx, &cy))._eq(&r_sq));
+
+        // |a - b|² = chord²
+        let chord = real_from_f64(context, chord)?;
+        let chord_sq = (&chord).mul(&chord);
+        solver.assert(&distance_squared((&ax, &ay), (&bx, &by))._eq(&chord_sq));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Points {:?} and {:?} on circle {:?} are {:.3}m apart",
+            self.a,
+            self.b,
+            self.circle,
+            self.chord.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_chord_length(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Circle(self.circle),
+            EntityRef::Point(self.a),
+            EntityRef::Point(self.b),
+        ]
+    }
+}
+
+/// Constraint that a circle cuts a chord of given length from a line
+///
+/// With `d` the perpendicular distance from the center to the (infinite)
+/// line, asserts `r² - d² = (chord/2)²`. Using the cross product
+/// `cross = (end - start) × (c - start)`, `d² = cross² / |end - start|²`; the
+/// equation is multiplied through by `|end - start|²` to avoid the division.
+#[derive(Debug, Clone)]
+pub struct ChordOnLineConstraint {
+    /// Circle intersecting the line
+    pub circle: CircleId,
+    /// Line carrying the chord
+    pub line: LineId,
+    /// Length of the chord cut from the line
+    pub chord: Length,
+}
+
+impl ChordOnLineConstraint {
+    /// Create a new chord-on-line constraint
+    pub fn new(circle: CircleId, line: LineId, chord: Length) -> Self {
+        Self {
+            circle,
+            line,
+            chord,
+        }
+    }
+}
+
+impl Constraint for ChordOnLineConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.chord.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Chord of circle {:?} on line {:?} cannot have negative length {:.3}m",
+                self.circle,
+                self.line,
+                self.chord.to_meters()
+            )));
+        }
+        let chord = self.chord.to_meters();
+
+        let (center, radius) = sketch.circle_center_and_radius(self.circle).map_err(|_| {
+            TextCadError::EntityError(format!("Circle {:?} not found", self.circle))
+        })?;
+        let (cx, cy) = sketch.point_variables(center).map_err(|_| {
+            TextCadError::EntityError(format!("Circle center {:?} not found", center))
+        })?;
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (x1, y1) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+        })?;
+        let (x2, y2) = sketch.point_variables(end_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+        })?;
+
+        // cross = (end - start) × (c - start)
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        let cross = (&dx).mul(&(&cy).sub(&y1)).sub(&(&dy).mul(&(&cx).sub(&x1)));
+        let len_sq = distance_squared((&x1, &y1), (&x2, &y2));
+
+        // (r² - (chord/2)²) · |L|² = cross²
+        let half = real_from_f64(context, chord / 2.0)?;
+        let r_sq = (&radius).mul(&radius);
+        let lhs = (&r_sq).sub(&(&half).mul(&half)).mul(&len_sq);
+        solver.assert(&lhs._eq(&(&cross).mul(&cross)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Circle {:?} cuts a {:.3}m chord from line {:?}",
+            self.circle,
+            self.chord.to_meters(),
+            self.line
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_chord_on_line(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Circle(self.circle), EntityRef::Line(self.line)]
+    }
+}
+
+/// Constraint that a line is a diameter of a circle
+///
+/// Asserts both endpoints lie on the circle (`|p - c|² = r²`) and that the
+/// circle's center is the line's midpoint, so the endpoints are antipodal.
+#[derive(Debug, Clone)]
+pub struct DiameterConstraint {
+    /// Line spanning the circle
+    pub line: LineId,
+    /// Circle the line is a diameter of
+    pub circle: CircleId,
+}
+
+impl DiameterConstraint {
+    /// Create a new diameter constraint
+    pub fn new(line: LineId, circle: CircleId) -> Self {
+        Self { line, circle }
+    }
+}
+
+impl Constraint for DiameterConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (center, radius) = sketch.circle_center_and_radius(self.circle).map_err(|_| {
+            TextCadError::EntityError(format!("Circle {:?} not found", self.circle))
+        })?;
+
+        let (cx, cy) = sketch.point_variables(center).map_err(|_| {
+            TextCadError::EntityError(format!("Circle center {:?} not found", center))
+        })?;
+        let (x1, y1) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+        })?;
+        let (x2, y2) = sketch.point_variables(end_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+        })?;
+
+        // Both endpoints on the circle
+        let r_sq = (&radius).mul(&radius);
+        solver.assert(&distance_squared((&x1, &y1), (&cx, &cy))._eq(&r_sq));
+        solver.assert(&distance_squared((&x2, &y2), (&cx, &cy))._eq(&r_sq));
+
+        // 2 * center = start + end
+        let two = Real::from_real(context, 2, 1);
+        solver.assert(&(&two).mul(&cx)._eq(&(&x1).add(&x2)));
+        solver.assert(&(&two).mul(&cy)._eq(&(&y1).add(&y2)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} is a diameter of circle {:?}",
+            self.line, self.circle
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_diameter(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Circle(self.circle)]
+    }
+}
+
+/// Constraint that a set of circles share one radius
+///
+/// Asserts each circle's radius equals the first circle's radius.
+#[derive(Debug, Clone)]
+pub struct AllEqualRadiusConstraint {
+    /// Circles whose radii are equalized
+    pub circles: Vec<CircleId>,
+}
+
+impl AllEqualRadiusConstraint {
+    /// Create a new equal radius constraint
+    pub fn new(circles: Vec<CircleId>) -> Self {
+        Self { circles }
+    }
+}
+
+impl Constraint for AllEqualRadiusConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.circles.len() < 2 {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Equal radius requires at least 2 circles, got {}",
+                self.circles.len()
+            )));
+        }
+
+        let radii = self
+            .circles
+            .iter()
+            .map(|&circle| {
+                sketch
+                    .circle_center_and_radius(circle)
+                    .map(|(_, radius)| radius)
+                    .map_err(|_| {
+                        TextCadError::EntityError(format!("Circle {:?} not found", circle))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for radius in &radii[1..] {
+            solver.assert(&radius._eq(&radii[0]));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Circles {:?} have equal radii", self.circles)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_all_equal_radius(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.circles
+            .iter()
+            .copied()
+            .map(EntityRef::Circle)
+            .collect()
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that ties a line's length to a fraction of a circle's circumference
+///
+/// Asserts `len(line) = fraction * 2π * radius`, where the line length is an
+/// explicit length variable (see `line_length_variable`) and 2π is encoded as
+/// a rational with six decimal places.
+#[derive(Debug, Clone)]
+pub struct LineLengthFromCircumferenceConstraint {
+    /// Line whose length is constrained
+    pub line: LineId,
+    /// Circle whose circumference drives the length
+    pub circle: CircleId,
+    /// Fraction of the circumference (1.0 = full wrap)
+    pub fraction: f64,
+}
+
+impl LineLengthFromCircumferenceConstraint {
+    /// Create a new circumference-fraction length constraint
+    pub fn new(line: LineId, circle: CircleId, fraction: f64) -> Self {
+        Self {
+            line,
+            circle,
+            fraction,
+        }
+    }
+}
+
+impl Constraint for LineLengthFromCircumferenceConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if !(self.fraction.is_finite() && self.fraction >= 0.0) {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Circumference fraction for line {:?} must be finite and non-negative, got {}",
+                self.line, self.fraction
+            )));
+        }
+
+        let (_, radius) = sketch.circle_center_and_radius(self.circle).map_err(|_| {
+            TextCadError::EntityError(format!("Circle {:?} not found", self.circle))
+        })?;
+        let len = line_length_variable(context, solver, sketch, self.line)?;
+
+        // len = fraction * 2π * r
+        let factor = real_from_f64(context, self.fraction * std::f64::consts::TAU)?;
+        solver.assert(&len._eq(&factor.mul(&radius)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} has length {:.3} * circumference of circle {:?}",
+            self.line, self.fraction, self.circle
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_line_length_from_circumference(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Circle(self.circle)]
+    }
+}
+
+/// Constraint that spaces circles evenly around a bolt circle
+///
+/// For N circles, asserts circle `i`'s center sits at angle `2π·i/N` and
+/// distance `radius` from `center`. Since the angles and radius are known,
+/// cos/sin are encoded as rationals and the constraint stays linear.
+#[derive(Debug, Clone)]
+pub struct BoltCircleConstraint {
+    /// Circles to place, in counter-clockwise order starting on the +x axis
+    pub circles: Vec<CircleId>,
+    /// Center of the bolt circle
+    pub center: PointId,
+    /// Radius of the bolt circle
+    pub radius: Length,
+}
+
+impl BoltCircleConstraint {
+    /// Create a new bolt circle constraint
+    pub fn new(circles: Vec<CircleId>, center: PointId, radius: Length) -> Self {
+        Self {
+            circles,
+            center,
+            radius,
+        }
+    }
+}
+
+impl Constraint for BoltCircleConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.circles.is_empty() {
+            return Err(TextCadError::InvalidConstraint(
+                "Bolt circle requires at least 1 circle".to_string(),
+            ));
+        }
+        if self.radius.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Bolt circle radius cannot be negative, got {:.3}m",
+                self.radius.to_meters()
+            )));
+        }
+
+        let (cx, cy) = sketch.point_variables(self.center).map_err(|_| {
+            TextCadError::EntityError(format!("Center point {:?} not found", self.center))
+        })?;
+
+        let n = self.circles.len() as f64;
+        let r = self.radius.to_meters();
+        for (i, &circle) in self.circles.iter().enumerate() {
+            let (hole_center, _) = sketch
+                .circle_center_and_radius(circle)
+                .map_err(|_| TextCadError::EntityError(format!("Circle {:?} not found", circle)))?;
+            let (hx, hy) = sketch.point_variables(hole_center).map_err(|_| {
+                TextCadError::EntityError(format!("Center point {:?} not found", hole_center))
+            })?;
+
+            // h = c + R·(cos θᵢ, sin θᵢ), θᵢ = 2π·i/N
+            let theta = std::f64::consts::TAU * i as f64 / n;
+            let offset_x = real_from_f64(context, r * theta.cos())?;
+            let offset_y = real_from_f64(context, r * theta.sin())?;
+            solver.assert(&hx._eq(&(&cx).add(&offset_x)));
+            solver.assert(&hy._eq(&(&cy).add(&offset_y)));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Circles {:?} evenly spaced on a {:.3}m bolt circle about {:?}",
+            self.circles,
+            self.radius.to_meters(),
+            self.center
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_bolt_circle(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        std::iter::once(EntityRef::Point(self.center))
+            .chain(self.circles.iter().copied().map(EntityRef::Circle))
+            .collect()
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that places a point where two circles intersect
+///
+/// Asserts the point lies on both circles (`|p - c₁|² = r₁²` and
+/// `|p - c₂|² = r₂²`). Two distinct circles meet in at most two points, so
+/// the solution is generally not unique: the solver picks either one unless
+/// another constraint (e.g. `PointSideOfLineConstraint`) rules one out.
+#[derive(Debug, Clone)]
+pub struct PointAtCircleIntersectionConstraint {
+    /// Point to place at the intersection
+    pub point: PointId,
+    /// First circle
+    pub c1: CircleId,
+    /// Second circle
+    pub c2: CircleId,
+}
+
+impl PointAtCircleIntersectionConstraint {
+    /// Create a new circle intersection constraint
+    pub fn new(point: PointId, c1: CircleId, c2: CircleId) -> Self {
+        Self { point, c1, c2 }
+    }
+}
+
+impl Constraint for PointAtCircleIntersectionConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (px, py) = sketch
+            .point_variables(self.point)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point)))?;
+
+        for circle in [self.c1, self.c2] {
+            let (center, radius) = sketch
+                .circle_center_and_radius(circle)
+                .map_err(|_| TextCadError::EntityError(format!("Circle {:?} not found", circle)))?;
+            let (cx, cy) = sketch.point_variables(center).map_err(|_| {
+                TextCadError::EntityError(format!("Center point {:?} not found", center))
+            })?;
+
+            // |p - c|² = r²
+            let dist_sq = distance_squared((&px, &py), (&cx, &cy));
+            solver.assert(&dist_sq._eq(&(&radius).mul(&radius)));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} at an intersection of circles {:?} and {:?}",
+            self.point, self.c1, self.c2
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_point_at_circle_intersection(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Point(self.point),
+            EntityRef::Circle(self.c1),
+            EntityRef::Circle(self.c2),
+        ]
+    }
+}
+
+/// Constraint that keeps two circles from overlapping
+///
+/// Asserts `|c1 - c2|² >= (r1 + r2)²`, so the circles may touch externally
+/// but not intersect. Assumes non-negative radii.
+#[derive(Debug, Clone)]
+pub struct CircleNonOverlapConstraint {
+    /// First circle
+    pub c1: CircleId,
+    /// Second circle
+    pub c2: CircleId,
+}
+
+impl CircleNonOverlapConstraint {
+    /// Create a new circle non-overlap constraint
+    pub fn new(c1: CircleId, c2: CircleId) -> Self {
+        Self { c1, c2 }
+    }
+}
+
+impl Constraint for CircleNonOverlapConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let circle = |id: CircleId| {
+            let (center, radius) = sketch
+                .circle_center_and_radius(id)
+                .map_err(|_| TextCadError::EntityError(format!("Circle {:?} not found", id)))?;
+            let (cx, cy) = sketch.point_variables(center).map_err(|_| {
+                TextCadError::EntityError(format!("Center point {:?} not found", center))
+            })?;
+            Ok::<_, TextCadError>((cx, cy, radius))
+        };
+        let (x1, y1, r1) = circle(self.c1)?;
+        let (x2, y2, r2) = circle(self.c2)?;
+
+        // |c1 - c2|² >= (r1 + r2)²
+        let dist_sq = distance_squared((&x1, &y1), (&x2, &y2));
+        let radius_sum = (&r1).add(&r2);
+        solver.assert(&dist_sq.ge(&(&radius_sum).mul(&radius_sum)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Circles {:?} and {:?} do not overlap", self.c1, self.c2)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_circle_non_overlap(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Circle(self.c1), EntityRef::Circle(self.c2)]
+    }
+}
+
+/// Squared distance between two points given their coordinate variables
+fn distance_squared<'ctx>(
+    (x1, y1): (&Real<'ctx>, &Real<'ctx>),
+    (x2, y2): (&Real<'ctx>, &Real<'ctx>),
+) -> Real<'ctx> {
+    let dx = x1.sub(x2);
+    let dy = y1.sub(y2);
+    (&dx).mul(&dx).add(&(&dy).mul(&dy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generational_arena::Index;
+    use std::collections::HashMap;
+    use z3::{Config, Context, SatResult, Solver};
+
+    // Mock implementation of SketchQuery for testing circle constraints
+    struct MockCircleSketch<'ctx> {
+        points: HashMap<PointId, (Real<'ctx>, Real<'ctx>)>,
+        circles: HashMap<CircleId, (PointId, Real<'ctx>)>,
+        lines: HashMap<LineId, (PointId, PointId)>,
+    }
+
+    impl<'ctx> MockCircleSketch<'ctx> {
+        fn new() -> Self {
+            Self {
+                points: HashMap::new(),
+                circles: HashMap::new(),
+                lines: HashMap::new(),
+            }
+        }
+
+        fn add_point(&mut self, id: PointId, x: Real<'ctx>, y: Real<'ctx>) {
+            self.points.insert(id, (x, y));
+        }
+
+        fn add_circle(&mut self, id: CircleId, center: PointId, radius: Real<'ctx>) {
+            self.circles.insert(id, (center, radius));
+        }
+
+        fn add_line(&mut self, id: LineId, start: PointId, end: PointId) {
+            self.lines.insert(id, (start, end));
+        }
+    }
+
+    impl<'ctx> SketchQuery for MockCircleSketch<'ctx> {
+        fn point_variables(&self, point_id: PointId) -> Result<(Real<'_>, Real<'_>)> {
+            self.points
+                .get(&point_id)
+                .map(|(x, y)| (x.clone(), y.clone()))
+                .ok_or_else(|| TextCadError::EntityError("Point not found".to_string()))
+        }
+
+        fn line_endpoints(&self, line_id: LineId) -> Result<(PointId, PointId)> {
+            self.lines
+                .get(&line_id)
+                .copied()
+                .ok_or_else(|| TextCadError::EntityError("Line not found".to_string()))
+        }
+
+        fn circle_center_and_radius(&self, circle_id: CircleId) -> Result<(PointId, Real<'_>)> {
+            self.circles
+                .get(&circle_id)
+                .map(|(center, radius)| (*center, radius.clone()))
+                .ok_or_else(|| TextCadError::EntityError("Circle not found".to_string()))
+        }
+
+        fn length_variable(&self, _name: &str) -> Result<Real<'_>> {
+            Err(TextCadError::InvalidConstraint(
+                "Not implemented".to_string(),
+            ))
+        }
+
+        fn angle_variable(&self, _name: &str) -> Result<Real<'_>> {
+            Err(TextCadError::InvalidConstraint(
+                "Not implemented".to_string(),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_point_on_circle_at_angle_constraint_creation() {
+        let circle = CircleId(Index::from_raw_parts(0, 0));
+        let point = PointId(Index::from_raw_parts(1, 0));
+
+        let constraint = PointOnCircleAtAngleConstraint::new(circle, point, Angle::degrees(45.0));
+
+        assert_eq!(constraint.circle, circle);
+        assert_eq!(constraint.point, point);
+        assert!(constraint.description().contains("45.000°"));
+    }
+
+    #[test]
+    fn test_point_on_circle_at_angle_zero() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let center = PointId(Index::from_raw_parts(0, 0));
+        let point = PointId(Index::from_raw_parts(1, 0));
+        let circle = CircleId(Index::from_raw_parts(0, 0));
+
+        let cx = Real::new_const(&ctx, "cx");
+        let cy = Real::new_const(&ctx, "cy");
+        let px = Real::new_const(&ctx, "px");
+        let py = Real::new_const(&ctx, "py");
+        let r = Real::new_const(&ctx, "r");
+
+        // Fix center at (1, 2) and radius at 3
+        solver.assert(&cx._eq(&Real::from_real(&ctx, 1, 1)));
+        solver.assert(&cy._eq(&Real::from_real(&ctx, 2, 1)));
+        solver.assert(&r._eq(&Real::from_real(&ctx, 3, 1)));
+
+        let mut mock_sketch = MockCircleSketch::new();
+        mock_sketch.add_point(center, cx, cy);
+        mock_sketch.add_point(point, px.clone(), py.clone());
+        mock_sketch.add_circle(circle, center, r);
+
+        let constraint = PointOnCircleAtAngleConstraint::new(circle, point, Angle::degrees(0.0));
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+
+        let x = model.eval(&px, true).unwrap().as_real().unwrap();
+        let y = model.eval(&py, true).unwrap().as_real().unwrap();
+        assert!((x.0 as f64 / x.1 as f64 - 4.0).abs() < 1e-6);
+        assert!((y.0 as f64 / y.1 as f64 - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_point_on_circle_at_angle_with_invalid_circle() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockCircleSketch::new();
+        let constraint = PointOnCircleAtAngleConstraint::new(
+            CircleId(Index::from_raw_parts(0, 0)),
+            PointId(Index::from_raw_parts(0, 0)),
+            Angle::degrees(90.0),
+        );
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::EntityError(_))));
+    }
+
+    fn fixed_points<'ctx>(
+        ctx: &'ctx Context,
+        solver: &Solver<'ctx>,
+        mock_sketch: &mut MockCircleSketch<'ctx>,
+        coords: &[(i32, i32)],
+    ) -> Vec<PointId> {
+        coords
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| {
+                let id = PointId(Index::from_raw_parts(i, 0));
+                let px = Real::new_const(ctx, format!("x{}", i));
+                let py = Real::new_const(ctx, format!("y{}", i));
+                solver.assert(&px._eq(&Real::from_real(ctx, x, 1)));
+                solver.assert(&py._eq(&Real::from_real(ctx, y, 1)));
+                mock_sketch.add_point(id, px, py);
+                id
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_concyclic_constraint_unit_circle_points() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockCircleSketch::new();
+        let points = fixed_points(
+            &ctx,
+            &solver,
+            &mut mock_sketch,
+            &[(1, 0), (0, 1), (-1, 0), (0, -1)],
+        );
+
+        let constraint = ConcyclicConstraint::new(points);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+    }
+
+    #[test]
+    fn test_concyclic_constraint_non_concyclic_points() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockCircleSketch::new();
+        let points = fixed_points(
+            &ctx,
+            &solver,
+            &mut mock_sketch,
+            &[(1, 0), (0, 1), (-1, 0), (0, -2)],
+        );
+
+        let constraint = ConcyclicConstraint::new(points);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Unsat);
+    }
+
+    #[test]
+    fn test_concyclic_constraint_too_few_points() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockCircleSketch::new();
+        let constraint = ConcyclicConstraint::new(vec![PointId(Index::from_raw_parts(0, 0))]);
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+    }
+
+    #[test]
+    fn test_radius_equals_line_length_constraint_creation() {
+        let circle = CircleId(Index::from_raw_parts(0, 0));
+        let line = LineId(Index::from_raw_parts(0, 0));
+
+        let constraint = RadiusEqualsLineLengthConstraint::new(circle, line, 0.5);
+
+        assert_eq!(constraint.circle, circle);
+        assert_eq!(constraint.line, line);
+        assert!(constraint.description().contains("0.500"));
+    }
+
+    #[test]
+    fn test_radius_equals_line_length_constraint_negative_scale() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockCircleSketch::new();
+        let constraint = RadiusEqualsLineLengthConstraint::new(
+            CircleId(Index::from_raw_parts(0, 0)),
+            LineId(Index::from_raw_parts(0, 0)),
+            -1.0,
+        );
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+    }
+
+    #[test]
+    fn test_chord_length_constraint_unit_circle_quarter_turn() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockCircleSketch::new();
+        let fixed = fixed_points(&ctx, &solver, &mut mock_sketch, &[(0, 0), (1, 0)]);
+        let (center, a) = (fixed[0], fixed[1]);
+
+        let b = PointId(Index::from_raw_parts(2, 0));
+        let bx = Real::new_const(&ctx, "bx");
+        let by = Real::new_const(&ctx, "by");
+        mock_sketch.add_point(b, bx.clone(), by.clone());
+
+        let circle = CircleId(Index::from_raw_parts(0, 0));
+        let radius = Real::new_const(&ctx, "r");
+        solver.assert(&radius._eq(&Real::from_real(&ctx, 1, 1)));
+        mock_sketch.add_circle(circle, center, radius);
+
+        let constraint = ChordLengthConstraint::new(circle, a, b, Length::meters(2.0_f64.sqrt()));
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let eval = |v: &Real| {
+            let (num, den) = model.eval(v, true).unwrap().as_real().unwrap();
+            num as f64 / den as f64
+        };
+
+        // a is at 0°, so b must sit at ±90°
+        let angle = eval(&by).atan2(eval(&bx)).to_degrees();
+        assert!((angle.abs() - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_chord_length_constraint_negative_chord() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockCircleSketch::new();
+        let constraint = ChordLengthConstraint::new(
+            CircleId(Index::from_raw_parts(0, 0)),
+            PointId(Index::from_raw_parts(0, 0)),
+            PointId(Index::from_raw_parts(1, 0)),
+            Length::meters(-1.0),
+        );
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+    }
+
+    #[test]
+    fn test_diameter_constraint_unit_circle() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockCircleSketch::new();
+        let center = fixed_points(&ctx, &solver, &mut mock_sketch, &[(0, 0)])[0];
+
+        let circle = CircleId(Index::from_raw_parts(0, 0));
+        let radius = Real::new_const(&ctx, "r");
+        solver.assert(&radius._eq(&Real::from_real(&ctx, 1, 1)));
+        mock_sketch.add_circle(circle, center, radius);
+
+        let a = PointId(Index::from_raw_parts(1, 0));
+        let b = PointId(Index::from_raw_parts(2, 0));
+        let (ax, ay) = (Real::new_const(&ctx, "ax"), Real::new_const(&ctx, "ay"));
+        let (bx, by) = (Real::new_const(&ctx, "bx"), Real::new_const(&ctx, "by"));
+        mock_sketch.add_point(a, ax.clone(), ay.clone());
+        mock_sketch.add_point(b, bx.clone(), by.clone());
+        // Pin the start so the solved coordinates are rational
+        solver.assert(&ay._eq(&Real::from_real(&ctx, 0, 1)));
+
+        let line = LineId(Index::from_raw_parts(0, 0));
+        mock_sketch.add_line(line, a, b);
+
+        let constraint = DiameterConstraint::new(line, circle);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let eval = |v: &Real| {
+            let (num, den) = model.eval(v, true).unwrap().as_real().unwrap();
+            num as f64 / den as f64
+        };
+        let (ax, ay, bx, by) = (eval(&ax), eval(&ay), eval(&bx), eval(&by));
+
+        assert!(((ax - bx).hypot(ay - by) - 2.0).abs() < 1e-10);
+        assert!(((ax + bx) / 2.0).abs() < 1e-10);
+        assert!(((ay + by) / 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_diameter_constraint_with_invalid_line() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockCircleSketch::new();
+        let constraint = DiameterConstraint::new(
+            LineId(Index::from_raw_parts(0, 0)),
+            CircleId(Index::from_raw_parts(0, 0)),
+        );
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::EntityError(_))));
+    }
+
+    #[test]
+    fn test_all_equal_radius_constraint_apply() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockCircleSketch::new();
+        let center = PointId(Index::from_raw_parts(0, 0));
+        let circles: Vec<CircleId> = (0..3)
+            .map(|i| {
+                let id = CircleId(Index::from_raw_parts(i, 0));
+                mock_sketch.add_circle(id, center, Real::new_const(&ctx, format!("r{}", i)));
+                id
+            })
+            .collect();
+
+        let constraint = AllEqualRadiusConstraint::new(circles);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.get_assertions().len(), 2);
+        assert!(constraint.description().contains("equal radii"));
+    }
+
+    #[test]
+    fn test_all_equal_radius_constraint_too_few_circles() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockCircleSketch::new();
+        let constraint = AllEqualRadiusConstraint::new(vec![CircleId(Index::from_raw_parts(0, 0))]);
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+    }
+
+    #[test]
+    fn test_line_length_from_circumference_negative_fraction() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockCircleSketch::new();
+        let constraint = LineLengthFromCircumferenceConstraint::new(
+            LineId(Index::from_raw_parts(0, 0)),
+            CircleId(Index::from_raw_parts(0, 0)),
+            -0.5,
+        );
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+    }
+}
@@ -0,0 +1,155 @@
+//! Ellipse-related constraints for geometric modeling
+//!
+//! Implements constraints on the semi-axes of Ellipse entities.
+
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::constraints::real_from_f64;
+use crate::entity::EllipseId;
+use crate::error::{Result, TextCadError};
+use crate::units::{Length, Scalar};
+use std::ops::Mul;
+use z3::ast::Ast;
+
+/// Constraint that fixes the shape of an ellipse but not its size
+///
+/// Asserts `semi_major = ratio · semi_minor`. The ratio must be at least 1
+/// so the semi-major axis stays the longer one.
+#[derive(Debug, Clone)]
+pub struct EllipseAspectRatioConstraint {
+    /// Ellipse to constrain
+    pub ellipse: EllipseId,
+    /// Ratio of semi-major to semi-minor axis
+    pub ratio: f64,
+}
+
+impl EllipseAspectRatioConstraint {
+    /// Create a new ellipse aspect ratio constraint
+    ///
+    /// `ratio` accepts either a raw f64 or a `Scalar`.
+    pub fn new(ellipse: EllipseId, ratio: impl Into<Scalar>) -> Self {
+        Self {
+            ellipse,
+            ratio: ratio.into().value(),
+        }
+    }
+}
+
+impl Constraint for EllipseAspectRatioConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if !(self.ratio.is_finite() && self.ratio >= 1.0) {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Aspect ratio for ellipse {:?} must be finite and at least 1, got {}",
+                self.ellipse, self.ratio
+            )));
+        }
+
+        let (_, semi_major, semi_minor) =
+            sketch.ellipse_center_and_axes(self.ellipse).map_err(|_| {
+                TextCadError::EntityError(format!("Ellipse {:?} not found", self.ellipse))
+            })?;
+
+        // semi_major = ratio * semi_minor
+        let ratio = real_from_f64(context, self.ratio)?;
+        solver.assert(&semi_major._eq(&(&ratio).mul(&semi_minor)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Ellipse {:?} has aspect ratio {:.3}",
+            self.ellipse, self.ratio
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_ellipse_aspect_ratio(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Ellipse(self.ellipse)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that fixes both semi-axes of an ellipse
+///
+/// Asserts `semi_major = a` and `semi_minor = b`. Negative lengths are
+/// rejected.
+#[derive(Debug, Clone)]
+pub struct EllipseSemiAxesConstraint {
+    /// Ellipse to constrain
+    pub ellipse: EllipseId,
+    /// Target semi-major axis (along x)
+    pub semi_major: Length,
+    /// Target semi-minor axis (along y)
+    pub semi_minor: Length,
+}
+
+impl EllipseSemiAxesConstraint {
+    /// Create a new ellipse semi-axes constraint
+    pub fn new(ellipse: EllipseId, semi_major: Length, semi_minor: Length) -> Self {
+        Self {
+            ellipse,
+            semi_major,
+            semi_minor,
+        }
+    }
+}
+
+impl Constraint for EllipseSemiAxesConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.semi_major.is_negative() || self.semi_minor.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Ellipse {:?} cannot have negative semi-axes {:.3}m and {:.3}m",
+                self.ellipse,
+                self.semi_major.to_meters(),
+                self.semi_minor.to_meters()
+            )));
+        }
+
+        let (_, semi_major, semi_minor) =
+            sketch.ellipse_center_and_axes(self.ellipse).map_err(|_| {
+                TextCadError::EntityError(format!("Ellipse {:?} not found", self.ellipse))
+            })?;
+
+        solver.assert(&semi_major._eq(&real_from_f64(context, self.semi_major.to_meters())?));
+        solver.assert(&semi_minor._eq(&real_from_f64(context, self.semi_minor.to_meters())?));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Ellipse {:?} has semi-axes {:.3}m and {:.3}m",
+            self.ellipse,
+            self.semi_major.to_meters(),
+            self.semi_minor.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_ellipse_semi_axes(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Ellipse(self.ellipse)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
@@ -3,10 +3,13 @@
 //! Implements constraints that apply to Line entities, including length constraints
 //! and future constraints like parallel/perpendicular relationships.
 
-use crate::constraint::{Constraint, SketchQuery};
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::constraints::basic::Side;
+use crate::constraints::real_from_f64;
+use crate::entities::PointId;
 use crate::entity::LineId;
 use crate::error::{Result, TextCadError};
-use crate::units::Length;
+use crate::units::{Angle, Length, Scalar};
 use std::ops::{Add, Mul, Sub};
 use z3::ast::{Ast, Real};
 
@@ -33,6 +36,15 @@ impl Constraint for LineLengthConstraint {
         solver: &z3::Solver,
         sketch: &dyn SketchQuery,
     ) -> Result<()> {
+        // Reject negative target lengths before touching the solver
+        if self.length.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Line {:?} cannot have negative length {:.3}m",
+                self.line,
+                self.length.to_meters()
+            )));
+        }
+
         // Get the line endpoints
         let (start_id, end_id) = sketch
             .line_endpoints(self.line)
@@ -51,11 +63,24 @@ impl Constraint for LineLengthConstraint {
         let dy = (&y2).sub(&y1);
         let dist_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
 
-        // Convert target length to Z3 rational value
-        // Use high precision by multiplying by 1_000_000
-        let target_meters = self.length.to_meters();
-        let target_sq = target_meters * target_meters;
-        let target_rational = Real::from_real(context, (target_sq * 1_000_000.0) as i32, 1_000_000);
+        // Convert target length squared to a Z3 rational value, using the
+        // exact rational when the length carries one
+        let target_rational = match self.length.exact_rational() {
+            Some((num, den)) => {
+                let target = Real::from_real_str(context, &num.to_string(), &den.to_string())
+                    .ok_or_else(|| {
+                        TextCadError::InvalidConstraint(format!(
+                            "Cannot encode length {}/{}m for line {:?}",
+                            num, den, self.line
+                        ))
+                    })?;
+                (&target).mul(&target)
+            }
+            None => {
+                let target = real_from_f64(context, self.length.to_meters())?;
+                (&target).mul(&target)
+            }
+        };
 
         // Assert that distance squared equals target squared
         solver.assert(&dist_sq._eq(&target_rational));
@@ -70,6 +95,14 @@ impl Constraint for LineLengthConstraint {
             self.length.to_meters()
         )
     }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_line_length(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line)]
+    }
 }
 
 #[cfg(test)]
@@ -226,6 +259,45 @@ mod tests {
         assert!(matches!(result, Err(TextCadError::EntityError(_))));
     }
 
+    #[test]
+    fn test_line_length_constraint_with_negative_length() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+        let line_id = LineId(Index::from_raw_parts(0, 0));
+
+        let mut mock_sketch = MockLineSketch::new();
+        mock_sketch.add_point(p1, Real::new_const(&ctx, "x1"), Real::new_const(&ctx, "y1"));
+        mock_sketch.add_point(p2, Real::new_const(&ctx, "x2"), Real::new_const(&ctx, "y2"));
+        mock_sketch.add_line(line_id, p1, p2);
+
+        let constraint = LineLengthConstraint::new(line_id, Length::meters(-5.0));
+
+        // Negative lengths are rejected at apply time without adding assertions
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+        assert_eq!(solver.get_assertions().len(), 0);
+    }
+
+    #[test]
+    fn test_linear_length_relation_constraint_creation() {
+        let target = LineId(Index::from_raw_parts(0, 0));
+        let source = LineId(Index::from_raw_parts(1, 0));
+
+        let constraint =
+            LinearLengthRelationConstraint::new(target, source, 2.0, Length::meters(1.0));
+
+        assert_eq!(constraint.target, target);
+        assert_eq!(constraint.source, source);
+        assert_eq!(constraint.scale, 2.0);
+        assert_eq!(constraint.offset, Length::meters(1.0));
+        assert!(constraint.description().contains("2.000"));
+        assert!(constraint.description().contains("1.000m"));
+    }
+
     #[test]
     fn test_parallel_lines_constraint_creation() {
         let line1_id = LineId(Index::from_raw_parts(0, 0));
@@ -513,6 +585,78 @@ mod tests {
     }
 }
 
+/// Constraint that bounds the length of a line from above
+///
+/// Asserts `(x2-x1)² + (y2-y1)² <= max²`, leaving the length free below the
+/// bound.
+#[derive(Debug, Clone)]
+pub struct MaxLengthConstraint {
+    /// Line to constrain
+    pub line: LineId,
+    /// Largest allowed length
+    pub max: Length,
+}
+
+impl MaxLengthConstraint {
+    /// Create a new maximum length constraint
+    pub fn new(line: LineId, max: Length) -> Self {
+        Self { line, max }
+    }
+}
+
+impl Constraint for MaxLengthConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.max.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Line {:?} cannot have negative maximum length {:.3}m",
+                self.line,
+                self.max.to_meters()
+            )));
+        }
+
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (x1, y1) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Start point {:?} not found", start_id))
+        })?;
+        let (x2, y2) = sketch
+            .point_variables(end_id)
+            .map_err(|_| TextCadError::EntityError(format!("End point {:?} not found", end_id)))?;
+
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        let dist_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
+
+        // dist² <= max²
+        let max = real_from_f64(context, self.max.to_meters())?;
+        solver.assert(&dist_sq.le(&(&max).mul(&max)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} is at most {:.3}m long",
+            self.line,
+            self.max.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_max_length(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line)]
+    }
+}
+
 /// Constraint that forces two lines to be parallel
 ///
 /// Uses the cross product method: two lines are parallel if their direction vectors
@@ -588,6 +732,14 @@ impl Constraint for ParallelLinesConstraint {
     fn description(&self) -> String {
         format!("Lines {:?} and {:?} are parallel", self.line1, self.line2)
     }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_parallel(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line1), EntityRef::Line(self.line2)]
+    }
 }
 
 /// Constraint that forces two lines to be perpendicular
@@ -668,4 +820,743 @@ impl Constraint for PerpendicularLinesConstraint {
             self.line1, self.line2
         )
     }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_perpendicular(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line1), EntityRef::Line(self.line2)]
+    }
+}
+
+/// Introduce a non-negative length variable for a line and tie it to the line's geometry
+///
+/// Since Z3 has no square root, the variable `len` is constrained by
+/// `len² = (x2-x1)² + (y2-y1)²` together with `len >= 0`. The variable is
+/// obtained through `SketchQuery::length_variable`, so every constraint that
+/// refers to the same line shares the same length variable.
+pub(crate) fn line_length_variable<'a>(
+    context: &z3::Context,
+    solver: &z3::Solver,
+    sketch: &'a dyn SketchQuery,
+    line: LineId,
+) -> Result<Real<'a>> {
+    let (start_id, end_id) = sketch
+        .line_endpoints(line)
+        .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", line)))?;
+
+    let (x1, y1) = sketch
+        .point_variables(start_id)
+        .map_err(|_| TextCadError::EntityError(format!("Start point {:?} not found", start_id)))?;
+    let (x2, y2) = sketch
+        .point_variables(end_id)
+        .map_err(|_| TextCadError::EntityError(format!("End point {:?} not found", end_id)))?;
+
+    let dx = (&x2).sub(&x1);
+    let dy = (&y2).sub(&y1);
+    let dist_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
+
+    let len = sketch.length_variable(&format!("line_{}", line.0.into_raw_parts().0))?;
+    let zero = Real::from_real(context, 0, 1);
+
+    // len² = dist² and len >= 0
+    solver.assert(&(&len).mul(&len)._eq(&dist_sq));
+    solver.assert(&len.ge(&zero));
+
+    Ok(len)
+}
+
+/// Constraint that ties one line's length to a linear function of another's
+///
+/// Asserts `len(target) = scale * len(source) + offset`. Both lengths are
+/// represented by explicit length variables (see `line_length_variable`), which
+/// keeps the relation itself linear.
+#[derive(Debug, Clone)]
+pub struct LinearLengthRelationConstraint {
+    /// Line whose length is determined by the relation
+    pub target: LineId,
+    /// Line whose length drives the relation
+    pub source: LineId,
+    /// Multiplier applied to the source length
+    pub scale: f64,
+    /// Offset added after scaling
+    pub offset: Length,
+}
+
+impl LinearLengthRelationConstraint {
+    /// Create a new linear length relation constraint
+    ///
+    /// `scale` accepts either a raw f64 or a `Scalar`.
+    pub fn new(target: LineId, source: LineId, scale: impl Into<Scalar>, offset: Length) -> Self {
+        Self {
+            target,
+            source,
+            scale: scale.into().value(),
+            offset,
+        }
+    }
+}
+
+impl Constraint for LinearLengthRelationConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let len_target = line_length_variable(context, solver, sketch, self.target)?;
+        let len_source = line_length_variable(context, solver, sketch, self.source)?;
+
+        let scale = real_from_f64(context, self.scale)?;
+        let offset = real_from_f64(context, self.offset.to_meters())?;
+
+        // len_target = scale * len_source + offset
+        let relation = (&scale).mul(&len_source).add(&offset);
+        solver.assert(&len_target._eq(&relation));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} has length {:.3} * length of line {:?} + {:.3}m",
+            self.target,
+            self.scale,
+            self.source,
+            self.offset.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_linear_length_relation(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.target), EntityRef::Line(self.source)]
+    }
+}
+
+/// Constraint that sets the opening angle between two lines at a shared vertex
+///
+/// Both lines must have `shared` as an endpoint. With outgoing direction
+/// vectors `u` and `v` from the vertex, asserts `(u·v)² = cos²θ·|u|²·|v|²`
+/// and that `u·v` has the sign of `cos θ`, which keeps the relation
+/// polynomial while distinguishing θ from 180° - θ.
+#[derive(Debug, Clone)]
+pub struct CornerAngleConstraint {
+    /// First line of the corner
+    pub line1: LineId,
+    /// Second line of the corner
+    pub line2: LineId,
+    /// Vertex shared by both lines
+    pub shared: PointId,
+    /// Opening angle between the lines
+    pub angle: Angle,
+}
+
+impl CornerAngleConstraint {
+    /// Create a new corner angle constraint
+    pub fn new(line1: LineId, line2: LineId, shared: PointId, angle: Angle) -> Self {
+        Self {
+            line1,
+            line2,
+            shared,
+            angle,
+        }
+    }
+
+    /// Get the far endpoint of `line` as seen from the shared vertex
+    fn far_endpoint(&self, sketch: &dyn SketchQuery, line: LineId) -> Result<PointId> {
+        let (start, end) = sketch
+            .line_endpoints(line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", line)))?;
+
+        if start == self.shared {
+            Ok(end)
+        } else if end == self.shared {
+            Ok(start)
+        } else {
+            Err(TextCadError::InvalidConstraint(format!(
+                "Line {:?} does not contain corner point {:?}",
+                line, self.shared
+            )))
+        }
+    }
+}
+
+impl Constraint for CornerAngleConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let far1 = self.far_endpoint(sketch, self.line1)?;
+        let far2 = self.far_endpoint(sketch, self.line2)?;
+
+        let (sx, sy) = sketch.point_variables(self.shared).map_err(|_| {
+            TextCadError::EntityError(format!("Corner point {:?} not found", self.shared))
+        })?;
+        let (x1, y1) = sketch
+            .point_variables(far1)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", far1)))?;
+        let (x2, y2) = sketch
+            .point_variables(far2)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", far2)))?;
+
+        // Outgoing direction vectors from the shared vertex
+        let ux = (&x1).sub(&sx);
+        let uy = (&y1).sub(&sy);
+        let vx = (&x2).sub(&sx);
+        let vy = (&y2).sub(&sy);
+
+        let dot = (&ux).mul(&vx).add(&(&uy).mul(&vy));
+        let u_sq = (&ux).mul(&ux).add(&(&uy).mul(&uy));
+        let v_sq = (&vx).mul(&vx).add(&(&vy).mul(&vy));
+
+        // (u·v)² = cos²θ · |u|² · |v|²
+        let cos = self.angle.cos();
+        let cos_real = real_from_f64(context, cos)?;
+        let cos_sq = (&cos_real).mul(&cos_real);
+        solver.assert(&(&dot).mul(&dot)._eq(&cos_sq.mul(&u_sq).mul(&v_sq)));
+
+        // u·v carries the sign of cos θ
+        let zero = Real::from_real(context, 0, 1);
+        if cos > 1e-9 {
+            solver.assert(&dot.ge(&zero));
+        } else if cos < -1e-9 {
+            solver.assert(&dot.le(&zero));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Lines {:?} and {:?} meet at {:?} with angle {:.1}°",
+            self.line1,
+            self.line2,
+            self.shared,
+            self.angle.to_degrees()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_corner_angle(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Line(self.line1),
+            EntityRef::Line(self.line2),
+            EntityRef::Point(self.shared),
+        ]
+    }
+}
+
+/// Side of the base line an offset line sits on, see `OffsetLineConstraint`
+pub type OffsetSide = Side;
+
+/// Constraint that places a line parallel to a base line at a signed offset
+///
+/// With the base line running from A to B, asserts for both endpoints P of the
+/// offset line that `(B - A) × (P - A) = ±distance · len(base)`, using `+` for
+/// `OffsetSide::Left` and `-` for `OffsetSide::Right`. Fixing the sign removes
+/// the ambiguity of which side the offset line lands on. The base length is an
+/// explicit length variable (see `line_length_variable`).
+#[derive(Debug, Clone)]
+pub struct OffsetLineConstraint {
+    /// Line the offset is measured from
+    pub base: LineId,
+    /// Line placed at the offset
+    pub offset_line: LineId,
+    /// Perpendicular distance between the lines
+    pub distance: Length,
+    /// Side of the base line the offset line sits on
+    pub side: OffsetSide,
+}
+
+impl OffsetLineConstraint {
+    /// Create a new offset line constraint
+    pub fn new(base: LineId, offset_line: LineId, distance: Length, side: OffsetSide) -> Self {
+        Self {
+            base,
+            offset_line,
+            distance,
+            side,
+        }
+    }
+}
+
+impl Constraint for OffsetLineConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.distance.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Offset of line {:?} from line {:?} cannot be negative, got {:.3}m",
+                self.offset_line,
+                self.base,
+                self.distance.to_meters()
+            )));
+        }
+
+        let (a_id, b_id) = sketch
+            .line_endpoints(self.base)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.base)))?;
+        let (p_id, q_id) = sketch.line_endpoints(self.offset_line).map_err(|_| {
+            TextCadError::EntityError(format!("Line {:?} not found", self.offset_line))
+        })?;
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (ax, ay) = point(a_id)?;
+        let (bx, by) = point(b_id)?;
+
+        let len = line_length_variable(context, solver, sketch, self.base)?;
+        let signed = match self.side {
+            Side::Left => self.distance.to_meters(),
+            Side::Right => -self.distance.to_meters(),
+        };
+        let target = real_from_f64(context, signed)?.mul(&len);
+
+        let dx = (&bx).sub(&ax);
+        let dy = (&by).sub(&ay);
+        for id in [p_id, q_id] {
+            // (B - A) × (P - A) = ±distance · len(base)
+            let (px, py) = point(id)?;
+            let cross = (&dx).mul(&(&py).sub(&ay)).sub(&(&dy).mul(&(&px).sub(&ax)));
+            solver.assert(&cross._eq(&target));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} is offset {:.3}m to the {:?} of line {:?}",
+            self.offset_line,
+            self.distance.to_meters(),
+            self.side,
+            self.base
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_offset_line(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Line(self.base),
+            EntityRef::Line(self.offset_line),
+        ]
+    }
+}
+
+/// Constraint that a line passes through the midpoint of a segment
+///
+/// Unlike a perpendicular bisector, the line may cross the segment at any
+/// angle. With `m = (a + b) / 2`, asserts the cross product
+/// `(end - start) × (m - start) = 0`, multiplied through by 2 as
+/// `(end - start) × (a + b - 2·start) = 0`. The midpoint may fall on the
+/// line's extension beyond its endpoints.
+#[derive(Debug, Clone)]
+pub struct ThroughMidpointConstraint {
+    /// Line that must pass through the midpoint
+    pub line: LineId,
+    /// First endpoint of the segment
+    pub seg_a: PointId,
+    /// Second endpoint of the segment
+    pub seg_b: PointId,
+}
+
+impl ThroughMidpointConstraint {
+    /// Create a new through-midpoint constraint
+    pub fn new(line: LineId, seg_a: PointId, seg_b: PointId) -> Self {
+        Self { line, seg_a, seg_b }
+    }
+}
+
+impl Constraint for ThroughMidpointConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (x1, y1) = point(start_id)?;
+        let (x2, y2) = point(end_id)?;
+        let (ax, ay) = point(self.seg_a)?;
+        let (bx, by) = point(self.seg_b)?;
+
+        // Twice the midpoint's offset from the line start: a + b - 2·start
+        let two = Real::from_real(context, 2, 1);
+        let mx = (&ax).add(&bx).sub(&(&two).mul(&x1));
+        let my = (&ay).add(&by).sub(&(&two).mul(&y1));
+
+        // (end - start) × (a + b - 2·start) = 0
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        let cross = (&dx).mul(&my).sub(&(&dy).mul(&mx));
+        solver.assert(&cross._eq(&Real::from_real(context, 0, 1)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} passes through the midpoint of points {:?} and {:?}",
+            self.line, self.seg_a, self.seg_b
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_through_midpoint(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Line(self.line),
+            EntityRef::Point(self.seg_a),
+            EntityRef::Point(self.seg_b),
+        ]
+    }
+}
+
+/// Constraint that forces two lines to point in the same direction
+///
+/// Stronger than `ParallelLinesConstraint`, which also allows opposite
+/// directions. Asserts the cross product of the direction vectors is zero
+/// and their dot product is strictly positive.
+#[derive(Debug, Clone)]
+pub struct CodirectionalConstraint {
+    /// First line to constrain
+    pub line1: LineId,
+    /// Second line to constrain
+    pub line2: LineId,
+}
+
+impl CodirectionalConstraint {
+    /// Create a new codirectional lines constraint
+    pub fn new(line1: LineId, line2: LineId) -> Self {
+        Self { line1, line2 }
+    }
+}
+
+impl Constraint for CodirectionalConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let direction = |line: LineId| {
+            let (start, end) = sketch
+                .line_endpoints(line)
+                .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", line)))?;
+            let (x1, y1) = point(start)?;
+            let (x2, y2) = point(end)?;
+            Ok::<_, TextCadError>(((&x2).sub(&x1), (&y2).sub(&y1)))
+        };
+
+        let (dx1, dy1) = direction(self.line1)?;
+        let (dx2, dy2) = direction(self.line2)?;
+        let zero = Real::from_real(context, 0, 1);
+
+        // v1 × v2 = 0
+        let cross = (&dx1).mul(&dy2).sub(&(&dy1).mul(&dx2));
+        solver.assert(&cross._eq(&zero));
+
+        // v1 · v2 > 0
+        let dot = (&dx1).mul(&dx2).add(&(&dy1).mul(&dy2));
+        solver.assert(&dot.gt(&zero));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Lines {:?} and {:?} point in the same direction",
+            self.line1, self.line2
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_codirectional(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line1), EntityRef::Line(self.line2)]
+    }
+}
+
+/// Constraint that sets a line's inclination from the downward vertical
+///
+/// With direction `d = end - start` and the down direction `(0, -1)`, asserts
+/// `d_y² = cos²θ·|d|²` and that `-d_y` has the sign of `cos θ`, so 0° points
+/// straight down and 180° straight up. Either side of the vertical satisfies
+/// the constraint.
+#[derive(Debug, Clone)]
+pub struct InclinationConstraint {
+    /// Line to constrain
+    pub line: LineId,
+    /// Angle between the line direction and straight down
+    pub from_vertical: Angle,
+}
+
+impl InclinationConstraint {
+    /// Create a new inclination constraint
+    pub fn new(line: LineId, from_vertical: Angle) -> Self {
+        Self {
+            line,
+            from_vertical,
+        }
+    }
+}
+
+impl Constraint for InclinationConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (x1, y1) = point(start_id)?;
+        let (x2, y2) = point(end_id)?;
+
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        let d_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
+
+        // (d · down)² = cos²θ · |d|², with d · down = -d_y
+        let cos = self.from_vertical.cos();
+        let cos_real = real_from_f64(context, cos)?;
+        let cos_sq = (&cos_real).mul(&cos_real);
+        solver.assert(&(&dy).mul(&dy)._eq(&cos_sq.mul(&d_sq)));
+
+        // -d_y carries the sign of cos θ
+        let zero = Real::from_real(context, 0, 1);
+        if cos > 1e-9 {
+            solver.assert(&dy.le(&zero));
+        } else if cos < -1e-9 {
+            solver.assert(&dy.ge(&zero));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} is inclined {:.1}° from the downward vertical",
+            self.line,
+            self.from_vertical.to_degrees()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_inclination(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line)]
+    }
+}
+
+/// Constraint that gives two lines the same slope magnitude
+///
+/// Asserts `(dy1/dx1)² = (dy2/dx2)²`, cross-multiplied to the polynomial
+/// `dy1²·dx2² = dy2²·dx1²`. The lines may rise or fall, as in the two sides
+/// of a symmetric gable. Because of the cross-multiplication, a vertical line
+/// (`dx = 0`) only matches another vertical line or a horizontal one.
+#[derive(Debug, Clone)]
+pub struct EqualInclinationConstraint {
+    /// First line to constrain
+    pub line1: LineId,
+    /// Second line to constrain
+    pub line2: LineId,
+}
+
+impl EqualInclinationConstraint {
+    /// Create a new equal inclination constraint
+    pub fn new(line1: LineId, line2: LineId) -> Self {
+        Self { line1, line2 }
+    }
+}
+
+impl Constraint for EqualInclinationConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let direction = |line: LineId| {
+            let (start, end) = sketch
+                .line_endpoints(line)
+                .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", line)))?;
+            let (x1, y1) = point(start)?;
+            let (x2, y2) = point(end)?;
+            Ok::<_, TextCadError>(((&x2).sub(&x1), (&y2).sub(&y1)))
+        };
+
+        let (dx1, dy1) = direction(self.line1)?;
+        let (dx2, dy2) = direction(self.line2)?;
+
+        // dy1²·dx2² = dy2²·dx1²
+        let lhs = (&dy1).mul(&dy1).mul(&(&dx2).mul(&dx2));
+        let rhs = (&dy2).mul(&dy2).mul(&(&dx1).mul(&dx1));
+        solver.assert(&lhs._eq(&rhs));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Lines {:?} and {:?} have equal inclination",
+            self.line1, self.line2
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_equal_inclination(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line1), EntityRef::Line(self.line2)]
+    }
+}
+
+/// Constraint that keeps a line's direction within an angular wedge
+///
+/// Angles are measured counter-clockwise from the positive x-axis. With
+/// direction `d = end - start` and boundary rays `u_min`, `u_max`, asserts
+/// `u_min × d ≥ 0` and `d × u_max ≥ 0`, the two half-planes whose
+/// intersection is the wedge. A positive dot product with the wedge bisector
+/// rules out the degenerate zero-length direction. The wedge must span
+/// strictly between 0° and 180° going counter-clockwise from `min` to `max`.
+#[derive(Debug, Clone)]
+pub struct AngleRangeConstraint {
+    /// Line to constrain
+    pub line: LineId,
+    /// Lower bound of the direction angle
+    pub min: Angle,
+    /// Upper bound of the direction angle
+    pub max: Angle,
+}
+
+impl AngleRangeConstraint {
+    /// Create a new angle range constraint
+    pub fn new(line: LineId, min: Angle, max: Angle) -> Self {
+        Self { line, min, max }
+    }
+}
+
+impl Constraint for AngleRangeConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let span = (self.max - self.min).normalize();
+        if span.is_zero(1e-9) || span.to_radians() >= std::f64::consts::PI {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Angle range from {:.1}° to {:.1}° must span between 0° and 180°",
+                self.min.to_degrees(),
+                self.max.to_degrees()
+            )));
+        }
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (x1, y1) = point(start_id)?;
+        let (x2, y2) = point(end_id)?;
+
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        let zero = Real::from_real(context, 0, 1);
+
+        // u_min × d = cos·dy - sin·dx ≥ 0
+        let min_cos = real_from_f64(context, self.min.cos())?;
+        let min_sin = real_from_f64(context, self.min.sin())?;
+        let min_cross = min_cos.mul(&dy).sub(&min_sin.mul(&dx));
+        solver.assert(&min_cross.ge(&zero));
+
+        // d × u_max = dx·sin - dy·cos ≥ 0
+        let max_cos = real_from_f64(context, self.max.cos())?;
+        let max_sin = real_from_f64(context, self.max.sin())?;
+        let max_cross = (&dx).mul(&max_sin).sub(&(&dy).mul(&max_cos));
+        solver.assert(&max_cross.ge(&zero));
+
+        // d · bisector > 0
+        let bisector = self.min + span / 2.0;
+        let bis_cos = real_from_f64(context, bisector.cos())?;
+        let bis_sin = real_from_f64(context, bisector.sin())?;
+        let dot = dx.mul(&bis_cos).add(&dy.mul(&bis_sin));
+        solver.assert(&dot.gt(&zero));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} direction lies between {:.1}° and {:.1}°",
+            self.line,
+            self.min.to_degrees(),
+            self.max.to_degrees()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_angle_range(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
 }
@@ -3,14 +3,114 @@
 //! This module contains specific constraint types that can be applied to
 //! geometric entities to define their relationships and properties.
 
+pub mod arc;
 pub mod basic;
+pub mod circle;
+pub mod ellipse;
 pub mod line;
 pub mod parametric;
+pub mod polygon;
+pub mod symmetry;
 
 #[cfg(test)]
 mod property_tests;
 
 // Re-export commonly used constraint types
-pub use basic::{CoincidentPointsConstraint, FixedPositionConstraint};
-pub use line::{LineLengthConstraint, ParallelLinesConstraint, PerpendicularLinesConstraint};
-pub use parametric::PointOnLineConstraint;
+pub use arc::{ArcSweepConstraint, ArcTangentConstraint, TangentJoinConstraint};
+pub use basic::{
+    CircumcenterConstraint, CoincidentPointsConstraint, EqualOffsetConstraint,
+    FixedCentroidConstraint, FixedPositionConstraint, HorizontalDistanceConstraint,
+    OrbitConstraint, PointSideOfLineConstraint, Side, VerticalDistanceConstraint,
+};
+pub use circle::{
+    AllEqualRadiusConstraint, BoltCircleConstraint, ChordLengthConstraint, ChordOnLineConstraint,
+    CircleNonOverlapConstraint, CircleRadiusConstraint, ConcyclicConstraint, DiameterConstraint,
+    LineLengthFromCircumferenceConstraint, PointAtCircleIntersectionConstraint,
+    PointOnCircleAtAngleConstraint, RadiusEqualsLineLengthConstraint,
+};
+pub use ellipse::{EllipseAspectRatioConstraint, EllipseSemiAxesConstraint};
+pub use line::{
+    AngleRangeConstraint, CodirectionalConstraint, CornerAngleConstraint,
+    EqualInclinationConstraint, InclinationConstraint, LineLengthConstraint,
+    LinearLengthRelationConstraint, MaxLengthConstraint, OffsetLineConstraint, OffsetSide,
+    ParallelLinesConstraint, PerpendicularLinesConstraint, ThroughMidpointConstraint,
+};
+pub use parametric::{
+    DistanceAlongLineConstraint, MidpointConstraint, MidpointOnLineConstraint,
+    OnAngleBisectorConstraint, PerpendicularAtEndpointConstraint, PointLineDistanceConstraint,
+    PointOnLineConstraint, ProjectionConstraint, ProportionalPointConstraint,
+    SegmentDivisionConstraint,
+};
+pub use polygon::{
+    ClosedLoopConstraint, ConvexPolygonConstraint, EqualDiagonalsConstraint, MinAreaConstraint,
+    ParallelogramConstraint, PerimeterConstraint, RhombusConstraint, TriangleAreaConstraint,
+};
+pub use symmetry::{LineSymmetryConstraint, RadialSymmetryConstraint, SymmetryConstraint};
+
+use crate::error::{Result, TextCadError};
+use z3::ast::Real;
+
+/// Encode an f64 exactly as a Z3 rational
+///
+/// Every finite f64 is a binary fraction `mantissa * 2^exponent`; both parts
+/// are written out as decimal integer strings, so values at any scale, from
+/// micrometres to kilometres, keep their full precision.
+pub(crate) fn real_from_f64<'ctx>(context: &'ctx z3::Context, value: f64) -> Result<Real<'ctx>> {
+    let invalid = || {
+        TextCadError::InvalidParameter(format!("Value {} cannot be encoded as a rational", value))
+    };
+    if !value.is_finite() {
+        return Err(invalid());
+    }
+
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1u64 << 52) - 1);
+    let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1u64 << 52), biased_exponent - 1075)
+    };
+    if mantissa == 0 {
+        return Ok(Real::from_real(context, 0, 1));
+    }
+    let trailing = mantissa.trailing_zeros();
+    mantissa >>= trailing;
+    exponent += trailing as i32;
+
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    let (num, den) = if exponent >= 0 {
+        (shifted_decimal(mantissa, exponent as u32), "1".to_string())
+    } else {
+        (mantissa.to_string(), shifted_decimal(1, exponent.unsigned_abs()))
+    };
+    Real::from_real_str(context, &format!("{}{}", sign, num), &den).ok_or_else(invalid)
+}
+
+/// Decimal digits of `value * 2^shift`, for integers wider than u128
+fn shifted_decimal(value: u64, shift: u32) -> String {
+    const LIMB: u64 = 1_000_000_000;
+
+    // Little-endian base-10^9 limbs
+    let mut limbs = vec![value % LIMB, value / LIMB % LIMB, value / LIMB / LIMB];
+    for _ in 0..shift {
+        let mut carry = 0;
+        for limb in &mut limbs {
+            let doubled = *limb * 2 + carry;
+            *limb = doubled % LIMB;
+            carry = doubled / LIMB;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+    }
+    while limbs.len() > 1 && limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+
+    let mut digits = limbs.pop().unwrap_or(0).to_string();
+    for limb in limbs.iter().rev() {
+        digits.push_str(&format!("{:09}", limb));
+    }
+    digits
+}
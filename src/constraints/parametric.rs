@@ -4,10 +4,12 @@
 //! These constraints automatically introduce parameter variables that are constrained
 //! within appropriate bounds to achieve the desired geometric properties.
 
-use crate::constraint::{Constraint, SketchQuery};
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::constraints::real_from_f64;
 use crate::entities::PointId;
 use crate::entity::LineId;
 use crate::error::{Result, TextCadError};
+use crate::units::Length;
 use std::ops::{Add, Mul, Sub};
 use z3::ast::{Ast, Real};
 
@@ -115,6 +117,773 @@ impl Constraint for PointOnLineConstraint {
             self.point, self.line
         )
     }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_point_on_line(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Point(self.point)]
+    }
+}
+
+/// Constraint that places a point dividing a line segment in the ratio m:n
+///
+/// Uses the parametric form `point = start + t * (end - start)` with the fixed
+/// parameter `t = m / (m + n)`, so the point is `m` parts from the start and
+/// `n` parts from the end. The ratio is computed by Z3 from the rational
+/// encodings of `m` and `n`.
+#[derive(Debug, Clone)]
+pub struct SegmentDivisionConstraint {
+    /// Line whose segment is divided
+    pub line: LineId,
+    /// Point placed at the division
+    pub point: PointId,
+    /// Parts from the start of the line
+    pub m: f64,
+    /// Parts from the end of the line
+    pub n: f64,
+}
+
+impl SegmentDivisionConstraint {
+    /// Create a new segment division constraint
+    pub fn new(line: LineId, point: PointId, m: f64, n: f64) -> Self {
+        Self { line, point, m, n }
+    }
+}
+
+impl Constraint for SegmentDivisionConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if !(self.m >= 0.0 && self.n >= 0.0 && self.m + self.n > 0.0) {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Segment division ratio {}:{} must be non-negative with a positive sum",
+                self.m, self.n
+            )));
+        }
+
+        // Get the line endpoints
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+
+        let (px, py) = sketch
+            .point_variables(self.point)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point)))?;
+        let (p1x, p1y) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+        })?;
+        let (p2x, p2y) = sketch.point_variables(end_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+        })?;
+
+        // t = m / (m + n)
+        let m = real_from_f64(context, self.m)?;
+        let n = real_from_f64(context, self.n)?;
+        let t = m.div(&(&m).add(&n));
+
+        // point = p1 + t * (p2 - p1)
+        let point_x = (&p1x).add(&(&t).mul(&(&p2x).sub(&p1x)));
+        let point_y = (&p1y).add(&(&t).mul(&(&p2y).sub(&p1y)));
+
+        solver.assert(&px._eq(&point_x));
+        solver.assert(&py._eq(&point_y));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} divides line segment {:?} in ratio {}:{}",
+            self.point, self.line, self.m, self.n
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_segment_division(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Point(self.point)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that places a point at the midpoint of a line segment
+///
+/// Asserts `2 * point = start + end`, the parametric form with `t = 1/2`.
+#[derive(Debug, Clone)]
+pub struct MidpointConstraint {
+    /// Line whose midpoint is taken
+    pub line: LineId,
+    /// Point placed at the midpoint
+    pub point: PointId,
+}
+
+impl MidpointConstraint {
+    /// Create a new midpoint constraint
+    pub fn new(line: LineId, point: PointId) -> Self {
+        Self { line, point }
+    }
+}
+
+impl Constraint for MidpointConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+
+        let (px, py) = sketch
+            .point_variables(self.point)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point)))?;
+        let (p1x, p1y) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+        })?;
+        let (p2x, p2y) = sketch.point_variables(end_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+        })?;
+
+        // 2 * p = p1 + p2
+        let two = Real::from_real(context, 2, 1);
+        solver.assert(&(&two).mul(&px)._eq(&(&p1x).add(&p2x)));
+        solver.assert(&(&two).mul(&py)._eq(&(&p1y).add(&p2y)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is the midpoint of line {:?}",
+            self.point, self.line
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_midpoint(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Point(self.point)]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that fixes the perpendicular distance from a point to a line
+///
+/// Uses the cross product `(x2-x1)*(py-y1) - (y2-y1)*(px-x1)`, which equals the
+/// signed distance times the line length, and asserts
+/// `cross² = distance² * line_length²`. The point may lie on either side.
+#[derive(Debug, Clone)]
+pub struct PointLineDistanceConstraint {
+    /// Point being positioned
+    pub point: PointId,
+    /// Line the distance is measured from
+    pub line: LineId,
+    /// Perpendicular distance from the point to the line
+    pub distance: Length,
+}
+
+impl PointLineDistanceConstraint {
+    /// Create a new point-to-line distance constraint
+    pub fn new(point: PointId, line: LineId, distance: Length) -> Self {
+        Self {
+            point,
+            line,
+            distance,
+        }
+    }
+}
+
+impl Constraint for PointLineDistanceConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.distance.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Point {:?} cannot have negative distance {:.3}m from line {:?}",
+                self.point,
+                self.distance.to_meters(),
+                self.line
+            )));
+        }
+        let distance = self.distance.to_meters();
+
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+
+        let (px, py) = sketch
+            .point_variables(self.point)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point)))?;
+        let (x1, y1) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+        })?;
+        let (x2, y2) = sketch.point_variables(end_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+        })?;
+
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        let cross = (&dx).mul(&(&py).sub(&y1)).sub(&(&dy).mul(&(&px).sub(&x1)));
+        let length_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
+
+        // cross² = distance² * |line|²
+        let distance = real_from_f64(context, distance)?;
+        let distance_sq = (&distance).mul(&distance);
+        solver.assert(&(&cross).mul(&cross)._eq(&distance_sq.mul(&length_sq)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is {:.3}m from line {:?}",
+            self.point,
+            self.distance.to_meters(),
+            self.line
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_point_line_distance(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Point(self.point), EntityRef::Line(self.line)]
+    }
+}
+
+/// Constraint that keeps a point on the perpendicular to a line at one endpoint
+///
+/// Asserts `(point - endpoint) · (end - start) = 0`, where `endpoint` is the
+/// line's end when `at_end` is true and its start otherwise.
+#[derive(Debug, Clone)]
+pub struct PerpendicularAtEndpointConstraint {
+    /// Line the perpendicular is erected on
+    pub line: LineId,
+    /// Use the line's end point (true) or start point (false)
+    pub at_end: bool,
+    /// Point kept on the perpendicular
+    pub point: PointId,
+}
+
+impl PerpendicularAtEndpointConstraint {
+    /// Create a new perpendicular-at-endpoint constraint
+    pub fn new(line: LineId, at_end: bool, point: PointId) -> Self {
+        Self {
+            line,
+            at_end,
+            point,
+        }
+    }
+}
+
+impl Constraint for PerpendicularAtEndpointConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+
+        let (px, py) = sketch
+            .point_variables(self.point)
+            .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", self.point)))?;
+        let (x1, y1) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+        })?;
+        let (x2, y2) = sketch.point_variables(end_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+        })?;
+
+        let (ex, ey) = if self.at_end { (&x2, &y2) } else { (&x1, &y1) };
+
+        // (p - e) · (end - start) = 0
+        let dot = (&px)
+            .sub(ex)
+            .mul(&(&x2).sub(&x1))
+            .add(&(&py).sub(ey).mul(&(&y2).sub(&y1)));
+        solver.assert(&dot._eq(&Real::from_real(context, 0, 1)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is on the perpendicular to line {:?} at its {}",
+            self.point,
+            self.line,
+            if self.at_end { "end" } else { "start" }
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_perpendicular_at_endpoint(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Point(self.point)]
+    }
+}
+
+/// Constraint that places a point at the orthogonal projection of another onto a line
+///
+/// Introduces a parameter t and asserts `foot = start + t * (end - start)`,
+/// together with `(source - foot) · (end - start) = 0`. The parameter is not
+/// bounded, so the foot may fall on the line's extension beyond the segment.
+#[derive(Debug, Clone)]
+pub struct ProjectionConstraint {
+    /// Point being projected
+    pub source: PointId,
+    /// Line projected onto
+    pub line: LineId,
+    /// Foot of the perpendicular from `source` to the line
+    pub foot: PointId,
+}
+
+impl ProjectionConstraint {
+    /// Create a new projection constraint
+    pub fn new(source: PointId, line: LineId, foot: PointId) -> Self {
+        Self { source, line, foot }
+    }
+}
+
+impl Constraint for ProjectionConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+
+        let (sx, sy) = sketch.point_variables(self.source).map_err(|_| {
+            TextCadError::EntityError(format!("Source point {:?} not found", self.source))
+        })?;
+        let (fx, fy) = sketch.point_variables(self.foot).map_err(|_| {
+            TextCadError::EntityError(format!("Foot point {:?} not found", self.foot))
+        })?;
+        let (p1x, p1y) = sketch.point_variables(start_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+        })?;
+        let (p2x, p2y) = sketch.point_variables(end_id).map_err(|_| {
+            TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+        })?;
+
+        let t_name = format!(
+            "t_projection_line_{}_foot_{}",
+            self.line.0.into_raw_parts().0,
+            self.foot.0.into_raw_parts().0
+        );
+        let t = Real::new_const(context, t_name);
+
+        let dx = (&p2x).sub(&p1x);
+        let dy = (&p2y).sub(&p1y);
+
+        // foot = p1 + t * (p2 - p1)
+        solver.assert(&fx._eq(&(&p1x).add(&(&t).mul(&dx))));
+        solver.assert(&fy._eq(&(&p1y).add(&(&t).mul(&dy))));
+
+        // (source - foot) · (p2 - p1) = 0
+        let dot = (&sx).sub(&fx).mul(&dx).add(&(&sy).sub(&fy).mul(&dy));
+        solver.assert(&dot._eq(&Real::from_real(context, 0, 1)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is the projection of point {:?} onto line {:?}",
+            self.foot, self.source, self.line
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_projection(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Point(self.source),
+            EntityRef::Line(self.line),
+            EntityRef::Point(self.foot),
+        ]
+    }
+}
+
+/// Constraint that places the midpoint of one line on another line segment
+///
+/// Useful for T-junctions. Introduces a parameter t in [0, 1] and asserts
+/// `(a + b) / 2 = start + t * (end - start)`, where `a` and `b` are the
+/// endpoints of `line` and `start`/`end` those of `onto`.
+#[derive(Debug, Clone)]
+pub struct MidpointOnLineConstraint {
+    /// Line whose midpoint is placed
+    pub line: LineId,
+    /// Line segment the midpoint must lie on
+    pub onto: LineId,
+}
+
+impl MidpointOnLineConstraint {
+    /// Create a new midpoint-on-line constraint
+    pub fn new(line: LineId, onto: LineId) -> Self {
+        Self { line, onto }
+    }
+}
+
+impl Constraint for MidpointOnLineConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let endpoints = |line: LineId| {
+            let (start_id, end_id) = sketch
+                .line_endpoints(line)
+                .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", line)))?;
+            let start = sketch.point_variables(start_id).map_err(|_| {
+                TextCadError::EntityError(format!("Line start point {:?} not found", start_id))
+            })?;
+            let end = sketch.point_variables(end_id).map_err(|_| {
+                TextCadError::EntityError(format!("Line end point {:?} not found", end_id))
+            })?;
+            Ok::<_, TextCadError>((start, end))
+        };
+
+        let ((ax, ay), (bx, by)) = endpoints(self.line)?;
+        let ((p1x, p1y), (p2x, p2y)) = endpoints(self.onto)?;
+
+        let t_name = format!(
+            "t_midpoint_line_{}_onto_{}",
+            self.line.0.into_raw_parts().0,
+            self.onto.0.into_raw_parts().0
+        );
+        let t = Real::new_const(context, t_name);
+
+        let half = Real::from_real(context, 1, 2);
+        let mx = (&ax).add(&bx).mul(&half);
+        let my = (&ay).add(&by).mul(&half);
+
+        // midpoint = p1 + t * (p2 - p1)
+        let dx = (&p2x).sub(&p1x);
+        let dy = (&p2y).sub(&p1y);
+        solver.assert(&mx._eq(&(&p1x).add(&(&t).mul(&dx))));
+        solver.assert(&my._eq(&(&p1y).add(&(&t).mul(&dy))));
+
+        // 0 <= t <= 1 keeps the midpoint within the segment
+        solver.assert(&t.ge(&Real::from_real(context, 0, 1)));
+        solver.assert(&t.le(&Real::from_real(context, 1, 1)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Midpoint of line {:?} lies on line segment {:?}",
+            self.line, self.onto
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_midpoint_on_line(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Line(self.onto)]
+    }
+}
+
+/// Constraint that places `b` between `a` and `c` so that `AB:BC = ratio`
+///
+/// Asserts `b - a = t * (c - a)` componentwise with `t = ratio / (1 + ratio)`,
+/// which keeps all three points collinear. A ratio of 1.618 gives the golden
+/// section.
+#[derive(Debug, Clone)]
+pub struct ProportionalPointConstraint {
+    /// Start of the segment
+    pub a: PointId,
+    /// Dividing point
+    pub b: PointId,
+    /// End of the segment
+    pub c: PointId,
+    /// Target ratio `AB:BC`
+    pub ratio: f64,
+}
+
+impl ProportionalPointConstraint {
+    /// Create a new proportional point constraint
+    pub fn new(a: PointId, b: PointId, c: PointId, ratio: f64) -> Self {
+        Self { a, b, c, ratio }
+    }
+}
+
+impl Constraint for ProportionalPointConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if !(self.ratio.is_finite() && self.ratio >= 0.0) {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Proportion ratio must be finite and non-negative, got {}",
+                self.ratio
+            )));
+        }
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (ax, ay) = point(self.a)?;
+        let (bx, by) = point(self.b)?;
+        let (cx, cy) = point(self.c)?;
+
+        // t = ratio / (1 + ratio)
+        let ratio = real_from_f64(context, self.ratio)?;
+        let t = ratio.div(&(&ratio).add(&Real::from_real(context, 1, 1)));
+
+        // b - a = t * (c - a)
+        solver.assert(&(&bx).sub(&ax)._eq(&(&t).mul(&(&cx).sub(&ax))));
+        solver.assert(&(&by).sub(&ay)._eq(&(&t).mul(&(&cy).sub(&ay))));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} divides {:?}-{:?} in ratio {}:1",
+            self.b, self.a, self.c, self.ratio
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_proportional_point(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Point(self.a),
+            EntityRef::Point(self.b),
+            EntityRef::Point(self.c),
+        ]
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that places a point on a line at a set distance from an endpoint
+///
+/// Introduces a parameter t in [0, 1] with `point = start + t * (end - start)`
+/// and asserts `|point - anchor|² = distance²`, where `anchor` is the start
+/// or end of the line. Unsatisfiable if the line is shorter than `distance`.
+#[derive(Debug, Clone)]
+pub struct DistanceAlongLineConstraint {
+    /// Line the point lies on
+    pub line: LineId,
+    /// Measure from the start point if true, else from the end point
+    pub from_start: bool,
+    /// Point to place
+    pub point: PointId,
+    /// Distance from the chosen endpoint
+    pub distance: Length,
+}
+
+impl DistanceAlongLineConstraint {
+    /// Create a new distance-along-line constraint
+    pub fn new(line: LineId, from_start: bool, point: PointId, distance: Length) -> Self {
+        Self {
+            line,
+            from_start,
+            point,
+            distance,
+        }
+    }
+}
+
+impl Constraint for DistanceAlongLineConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.distance.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Distance along line must be non-negative, got {}",
+                self.distance
+            )));
+        }
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.line)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line)))?;
+        let (x1, y1) = point(start_id)?;
+        let (x2, y2) = point(end_id)?;
+        let (px, py) = point(self.point)?;
+
+        let t_name = format!(
+            "t_distance_along_line_{}_point_{}",
+            self.line.0.into_raw_parts().0,
+            self.point.0.into_raw_parts().0
+        );
+        let t = Real::new_const(context, t_name);
+
+        // point = start + t * (end - start)
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        solver.assert(&px._eq(&(&x1).add(&(&t).mul(&dx))));
+        solver.assert(&py._eq(&(&y1).add(&(&t).mul(&dy))));
+
+        // 0 <= t <= 1 keeps the point within the segment
+        solver.assert(&t.ge(&Real::from_real(context, 0, 1)));
+        solver.assert(&t.le(&Real::from_real(context, 1, 1)));
+
+        // |point - anchor|² = distance²
+        let (ax, ay) = if self.from_start { (x1, y1) } else { (x2, y2) };
+        let ox = (&px).sub(&ax);
+        let oy = (&py).sub(&ay);
+        let dist_sq = (&ox).mul(&ox).add(&(&oy).mul(&oy));
+        let distance = real_from_f64(context, self.distance.to_meters())?;
+        solver.assert(&dist_sq._eq(&(&distance).mul(&distance)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} lies on line {:?}, {:.3}m from its {}",
+            self.point,
+            self.line,
+            self.distance.to_meters(),
+            if self.from_start { "start" } else { "end" }
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_distance_along_line(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![EntityRef::Line(self.line), EntityRef::Point(self.point)]
+    }
+}
+
+/// Constraint that keeps a point equidistant from two lines
+///
+/// With `c_i` the cross product `(end_i - start_i) × (p - start_i)`, which is
+/// the signed distance from the point to line `i` times that line's length,
+/// asserts `c_1²·|L_2|² = c_2²·|L_1|²`. The point may lie on either bisector
+/// of the two lines, and the lines are treated as infinite.
+#[derive(Debug, Clone)]
+pub struct OnAngleBisectorConstraint {
+    /// First line
+    pub line1: LineId,
+    /// Second line
+    pub line2: LineId,
+    /// Point kept on the bisector
+    pub point: PointId,
+}
+
+impl OnAngleBisectorConstraint {
+    /// Create a new on-angle-bisector constraint
+    pub fn new(line1: LineId, line2: LineId, point: PointId) -> Self {
+        Self {
+            line1,
+            line2,
+            point,
+        }
+    }
+}
+
+impl Constraint for OnAngleBisectorConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (px, py) = point(self.point)?;
+
+        // (cross, |line|²) for the point against a line
+        let measure = |line: LineId| {
+            let (start_id, end_id) = sketch
+                .line_endpoints(line)
+                .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", line)))?;
+            let (x1, y1) = point(start_id)?;
+            let (x2, y2) = point(end_id)?;
+            let dx = (&x2).sub(&x1);
+            let dy = (&y2).sub(&y1);
+            let cross = (&dx).mul(&(&py).sub(&y1)).sub(&(&dy).mul(&(&px).sub(&x1)));
+            let length_sq = (&dx).mul(&dx).add(&(&dy).mul(&dy));
+            Ok::<_, TextCadError>((cross, length_sq))
+        };
+        let (cross1, length1_sq) = measure(self.line1)?;
+        let (cross2, length2_sq) = measure(self.line2)?;
+
+        // c1²·|L2|² = c2²·|L1|²
+        let lhs = (&cross1).mul(&cross1).mul(&length2_sq);
+        let rhs = (&cross2).mul(&cross2).mul(&length1_sq);
+        solver.assert(&lhs._eq(&rhs));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Point {:?} is equidistant from lines {:?} and {:?}",
+            self.point, self.line1, self.line2
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_on_angle_bisector(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Line(self.line1),
+            EntityRef::Line(self.line2),
+            EntityRef::Point(self.point),
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -125,7 +894,7 @@ mod tests {
     use generational_arena::Index;
     use std::collections::HashMap;
     use z3::ast::Real;
-    use z3::{Config, Context, Solver};
+    use z3::{Config, Context, SatResult, Solver};
 
     // Mock implementation of SketchQuery for testing parametric constraints
     struct MockParametricSketch<'ctx> {
@@ -575,4 +1344,188 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_segment_division_constraint_three_to_one() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+        let p = PointId(Index::from_raw_parts(2, 0));
+        let line_id = LineId(Index::from_raw_parts(0, 0));
+
+        let x1 = Real::new_const(&ctx, "x1");
+        let y1 = Real::new_const(&ctx, "y1");
+        let x2 = Real::new_const(&ctx, "x2");
+        let y2 = Real::new_const(&ctx, "y2");
+        let px = Real::new_const(&ctx, "px");
+        let py = Real::new_const(&ctx, "py");
+
+        // Line from (0,0) to (10,0)
+        let zero = Real::from_real(&ctx, 0, 1);
+        solver.assert(&x1._eq(&zero));
+        solver.assert(&y1._eq(&zero));
+        solver.assert(&x2._eq(&Real::from_real(&ctx, 10, 1)));
+        solver.assert(&y2._eq(&zero));
+
+        let mut mock_sketch = MockParametricSketch::new();
+        mock_sketch.add_point(p1, x1, y1);
+        mock_sketch.add_point(p2, x2, y2);
+        mock_sketch.add_point(p, px.clone(), py.clone());
+        mock_sketch.add_line(line_id, p1, p2);
+
+        let constraint = SegmentDivisionConstraint::new(line_id, p, 3.0, 1.0);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let (xn, xd) = model.eval(&px, true).unwrap().as_real().unwrap();
+        let (yn, yd) = model.eval(&py, true).unwrap().as_real().unwrap();
+        assert!((xn as f64 / xd as f64 - 7.5).abs() < 1e-10);
+        assert!((yn as f64 / yd as f64).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_segment_division_constraint_invalid_ratio() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockParametricSketch::new();
+        let line_id = LineId(Index::from_raw_parts(0, 0));
+        let p = PointId(Index::from_raw_parts(0, 0));
+
+        for (m, n) in [(0.0, 0.0), (-1.0, 2.0), (f64::NAN, 1.0)] {
+            let constraint = SegmentDivisionConstraint::new(line_id, p, m, n);
+            let result = constraint.apply(&ctx, &solver, &mock_sketch);
+            assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+        }
+    }
+
+    #[test]
+    fn test_midpoint_constraint_apply() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+        let m = PointId(Index::from_raw_parts(2, 0));
+        let line_id = LineId(Index::from_raw_parts(0, 0));
+
+        let mut mock_sketch = MockParametricSketch::new();
+        mock_sketch.add_point(p1, Real::new_const(&ctx, "x1"), Real::new_const(&ctx, "y1"));
+        mock_sketch.add_point(p2, Real::new_const(&ctx, "x2"), Real::new_const(&ctx, "y2"));
+        mock_sketch.add_point(m, Real::new_const(&ctx, "mx"), Real::new_const(&ctx, "my"));
+        mock_sketch.add_line(line_id, p1, p2);
+
+        let constraint = MidpointConstraint::new(line_id, m);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.get_assertions().len(), 2);
+        assert!(constraint.description().contains("midpoint"));
+    }
+
+    #[test]
+    fn test_point_line_distance_constraint_horizontal_line() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let p1 = PointId(Index::from_raw_parts(0, 0));
+        let p2 = PointId(Index::from_raw_parts(1, 0));
+        let p = PointId(Index::from_raw_parts(2, 0));
+        let line_id = LineId(Index::from_raw_parts(0, 0));
+
+        let x1 = Real::new_const(&ctx, "x1");
+        let y1 = Real::new_const(&ctx, "y1");
+        let x2 = Real::new_const(&ctx, "x2");
+        let y2 = Real::new_const(&ctx, "y2");
+        let px = Real::new_const(&ctx, "px");
+        let py = Real::new_const(&ctx, "py");
+
+        // Horizontal line at y=0 from (0,0) to (10,0)
+        let zero = Real::from_real(&ctx, 0, 1);
+        solver.assert(&x1._eq(&zero));
+        solver.assert(&y1._eq(&zero));
+        solver.assert(&x2._eq(&Real::from_real(&ctx, 10, 1)));
+        solver.assert(&y2._eq(&zero));
+
+        let mut mock_sketch = MockParametricSketch::new();
+        mock_sketch.add_point(p1, x1, y1);
+        mock_sketch.add_point(p2, x2, y2);
+        mock_sketch.add_point(p, px, py.clone());
+        mock_sketch.add_line(line_id, p1, p2);
+
+        let constraint = PointLineDistanceConstraint::new(p, line_id, Length::meters(3.0));
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let (yn, yd) = model.eval(&py, true).unwrap().as_real().unwrap();
+        assert!(((yn as f64 / yd as f64).abs() - 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_point_line_distance_constraint_negative_distance() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockParametricSketch::new();
+        let constraint = PointLineDistanceConstraint::new(
+            PointId(Index::from_raw_parts(0, 0)),
+            LineId(Index::from_raw_parts(0, 0)),
+            Length::meters(-1.0),
+        );
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+    }
+
+    #[test]
+    fn test_perpendicular_at_endpoint_constraint_horizontal_line() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+
+        for (at_end, expected_x) in [(true, 4.0), (false, 0.0)] {
+            let solver = Solver::new(&ctx);
+
+            let p1 = PointId(Index::from_raw_parts(0, 0));
+            let p2 = PointId(Index::from_raw_parts(1, 0));
+            let p = PointId(Index::from_raw_parts(2, 0));
+            let line_id = LineId(Index::from_raw_parts(0, 0));
+
+            let x1 = Real::new_const(&ctx, "x1");
+            let y1 = Real::new_const(&ctx, "y1");
+            let x2 = Real::new_const(&ctx, "x2");
+            let y2 = Real::new_const(&ctx, "y2");
+            let px = Real::new_const(&ctx, "px");
+            let py = Real::new_const(&ctx, "py");
+
+            // Horizontal line from (0,0) to (4,0), point at height 2
+            let zero = Real::from_real(&ctx, 0, 1);
+            solver.assert(&x1._eq(&zero));
+            solver.assert(&y1._eq(&zero));
+            solver.assert(&x2._eq(&Real::from_real(&ctx, 4, 1)));
+            solver.assert(&y2._eq(&zero));
+            solver.assert(&py._eq(&Real::from_real(&ctx, 2, 1)));
+
+            let mut mock_sketch = MockParametricSketch::new();
+            mock_sketch.add_point(p1, x1, y1);
+            mock_sketch.add_point(p2, x2, y2);
+            mock_sketch.add_point(p, px.clone(), py);
+            mock_sketch.add_line(line_id, p1, p2);
+
+            let constraint = PerpendicularAtEndpointConstraint::new(line_id, at_end, p);
+            constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+            assert_eq!(solver.check(), SatResult::Sat);
+            let model = solver.get_model().unwrap();
+            let (xn, xd) = model.eval(&px, true).unwrap().as_real().unwrap();
+            assert!((xn as f64 / xd as f64 - expected_x).abs() < 1e-10);
+        }
+    }
 }
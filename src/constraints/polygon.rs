@@ -0,0 +1,865 @@
+//! Polygon-related constraints for geometric modeling
+//!
+//! Implements constraints that relate chains of Line entities.
+
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::constraints::line::line_length_variable;
+use crate::constraints::real_from_f64;
+use crate::entities::PointId;
+use crate::entity::LineId;
+use crate::error::{Result, TextCadError};
+use crate::units::{Area, Length};
+use std::ops::{Add, Mul, Sub};
+use z3::ast::{Ast, Real};
+
+/// Constraint that joins a sequence of lines into a closed loop
+///
+/// For each consecutive pair, the end point of a line is made coincident with
+/// the start point of the next line, and the last line's end is joined to the
+/// first line's start. Useful when lines were created with independent endpoints.
+#[derive(Debug, Clone)]
+pub struct ClosedLoopConstraint {
+    /// Lines forming the loop, in order
+    pub lines: Vec<LineId>,
+}
+
+impl ClosedLoopConstraint {
+    /// Create a new closed loop constraint
+    pub fn new(lines: Vec<LineId>) -> Self {
+        Self { lines }
+    }
+}
+
+impl Constraint for ClosedLoopConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.lines.len() < 3 {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Closed loop requires at least 3 lines, got {}",
+                self.lines.len()
+            )));
+        }
+
+        for (i, &line) in self.lines.iter().enumerate() {
+            let next = self.lines[(i + 1) % self.lines.len()];
+
+            let (_, end) = sketch
+                .line_endpoints(line)
+                .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", line)))?;
+            let (next_start, _) = sketch
+                .line_endpoints(next)
+                .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", next)))?;
+
+            // Lines already sharing the joint point need no assertion
+            if end == next_start {
+                continue;
+            }
+
+            let (x1, y1) = sketch
+                .point_variables(end)
+                .map_err(|_| TextCadError::EntityError(format!("End point {:?} not found", end)))?;
+            let (x2, y2) = sketch.point_variables(next_start).map_err(|_| {
+                TextCadError::EntityError(format!("Start point {:?} not found", next_start))
+            })?;
+
+            solver.assert(&x1._eq(&x2));
+            solver.assert(&y1._eq(&y2));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Lines {:?} form a closed loop", self.lines)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_closed_loop(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.lines.iter().copied().map(EntityRef::Line).collect()
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
+
+/// Constraint that the polygon through the given points encloses at least an area
+///
+/// Uses the shoelace formula: the signed sum `S = sum(x_i*y_{i+1} - x_{i+1}*y_i)`
+/// equals twice the signed area, so `S^2 >= (2*min)^2` bounds the enclosed area
+/// regardless of winding direction.
+#[derive(Debug, Clone)]
+pub struct MinAreaConstraint {
+    /// Polygon vertices, in order
+    pub points: Vec<PointId>,
+    /// Minimum enclosed area
+    pub min: Area,
+}
+
+impl MinAreaConstraint {
+    /// Create a new minimum area constraint
+    pub fn new(points: Vec<PointId>, min: Area) -> Self {
+        Self { points, min }
+    }
+}
+
+impl Constraint for MinAreaConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.points.len() < 3 {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Minimum area requires at least 3 points, got {}",
+                self.points.len()
+            )));
+        }
+
+        if self.min.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Minimum area cannot be negative, got {:.3}m²",
+                self.min.to_square_meters()
+            )));
+        }
+        let min = self.min.to_square_meters();
+
+        let vars = self
+            .points
+            .iter()
+            .map(|&p| {
+                sketch
+                    .point_variables(p)
+                    .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", p)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut terms = Vec::with_capacity(vars.len());
+        for (i, (xi, yi)) in vars.iter().enumerate() {
+            let (xj, yj) = &vars[(i + 1) % vars.len()];
+            terms.push(xi.mul(yj).sub(&xj.mul(yi)));
+        }
+        let term_refs: Vec<&Real> = terms.iter().collect();
+        let twice_area = Real::add(context, &term_refs);
+
+        let bound = real_from_f64(context, 2.0 * min)?;
+        solver.assert(&(&twice_area).mul(&twice_area).ge(&(&bound).mul(&bound)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Polygon {:?} encloses at least {} m²",
+            self.points,
+            self.min.to_square_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_min_area(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.points.iter().copied().map(EntityRef::Point).collect()
+    }
+}
+
+/// Constraint that a polygon is strictly convex
+///
+/// At each vertex the cross product of the incoming and outgoing edges gives
+/// the turn direction. Asserts every cross product has the same sign as the
+/// first (`c_i * c_0 > 0`), so the polygon turns consistently left or right.
+#[derive(Debug, Clone)]
+pub struct ConvexPolygonConstraint {
+    /// Polygon vertices, in order
+    pub vertices: Vec<PointId>,
+}
+
+impl ConvexPolygonConstraint {
+    /// Create a new convex polygon constraint
+    pub fn new(vertices: Vec<PointId>) -> Self {
+        Self { vertices }
+    }
+}
+
+impl Constraint for ConvexPolygonConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let n = self.vertices.len();
+        if n < 3 {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Convex polygon requires at least 3 vertices, got {}",
+                n
+            )));
+        }
+
+        let vars = self
+            .vertices
+            .iter()
+            .map(|&p| {
+                sketch
+                    .point_variables(p)
+                    .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", p)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Turn at vertex i: (v_i - v_{i-1}) x (v_{i+1} - v_i)
+        let crosses: Vec<Real> = (0..n)
+            .map(|i| {
+                let (x0, y0) = &vars[(i + n - 1) % n];
+                let (x1, y1) = &vars[i];
+                let (x2, y2) = &vars[(i + 1) % n];
+                x1.sub(x0)
+                    .mul(&y2.sub(y1))
+                    .sub(&y1.sub(y0).mul(&x2.sub(x1)))
+            })
+            .collect();
+
+        let zero = Real::from_real(context, 0, 1);
+        for cross in &crosses[1..] {
+            solver.assert(&cross.mul(&crosses[0]).gt(&zero));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Polygon {:?} is convex", self.vertices)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_convex_polygon(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.vertices
+            .iter()
+            .copied()
+            .map(EntityRef::Point)
+            .collect()
+    }
+}
+
+/// Constraint that fixes the total length of a chain of lines
+///
+/// Each line gets a non-negative length variable (`len² = dist²`, see
+/// `line_length_variable`) and the sum of those variables must equal
+/// `total`. The lines need not be joined; combine with
+/// `ClosedLoopConstraint` for a closed perimeter.
+#[derive(Debug, Clone)]
+pub struct PerimeterConstraint {
+    /// Lines whose lengths are summed
+    pub lines: Vec<LineId>,
+    /// Required total length
+    pub total: Length,
+}
+
+impl PerimeterConstraint {
+    /// Create a new perimeter constraint
+    pub fn new(lines: Vec<LineId>, total: Length) -> Self {
+        Self { lines, total }
+    }
+}
+
+impl Constraint for PerimeterConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.lines.is_empty() {
+            return Err(TextCadError::InvalidConstraint(
+                "Perimeter requires at least 1 line".to_string(),
+            ));
+        }
+        if self.total.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Perimeter cannot be negative, got {:.3}m",
+                self.total.to_meters()
+            )));
+        }
+
+        let mut sum = Real::from_real(context, 0, 1);
+        for &line in &self.lines {
+            let len = line_length_variable(context, solver, sketch, line)?;
+            sum = (&sum).add(&len);
+        }
+
+        solver.assert(&sum._eq(&real_from_f64(context, self.total.to_meters())?));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Lines {:?} have a total length of {:.3}m",
+            self.lines,
+            self.total.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_perimeter(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.lines.iter().copied().map(EntityRef::Line).collect()
+    }
+}
+
+/// Constraint that fixes the area of the triangle through three points
+///
+/// The cross product `(b - a) × (c - a)` is twice the signed area, so
+/// `cross² = (2·area)²` fixes the area regardless of winding direction.
+#[derive(Debug, Clone)]
+pub struct TriangleAreaConstraint {
+    /// First vertex
+    pub a: PointId,
+    /// Second vertex
+    pub b: PointId,
+    /// Third vertex
+    pub c: PointId,
+    /// Required area
+    pub area: Area,
+}
+
+impl TriangleAreaConstraint {
+    /// Create a new triangle area constraint
+    pub fn new(a: PointId, b: PointId, c: PointId, area: Area) -> Self {
+        Self { a, b, c, area }
+    }
+}
+
+impl Constraint for TriangleAreaConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.area.is_negative() {
+            return Err(TextCadError::InvalidConstraint(format!(
+                "Triangle area cannot be negative, got {:.3}m²",
+                self.area.to_square_meters()
+            )));
+        }
+        let area = self.area.to_square_meters();
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (ax, ay) = point(self.a)?;
+        let (bx, by) = point(self.b)?;
+        let (cx, cy) = point(self.c)?;
+
+        // cross = (b - a) × (c - a)
+        let cross = (&bx)
+            .sub(&ax)
+            .mul(&(&cy).sub(&ay))
+            .sub(&(&by).sub(&ay).mul(&(&cx).sub(&ax)));
+
+        // cross² = (2·area)²
+        let twice_area = real_from_f64(context, 2.0 * area)?;
+        solver.assert(&(&cross).mul(&cross)._eq(&(&twice_area).mul(&twice_area)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Triangle {:?}, {:?}, {:?} has area {} m²",
+            self.a,
+            self.b,
+            self.c,
+            self.area.to_square_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_triangle_area(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Point(self.a),
+            EntityRef::Point(self.b),
+            EntityRef::Point(self.c),
+        ]
+    }
+}
+
+/// Constraint that four points form a parallelogram
+///
+/// For vertices `v0..v3` in order, asserts that each pair of opposite sides
+/// is parallel (zero cross product of their directions) and of equal length
+/// (equal squared lengths). Parallel and equal opposite sides leave two
+/// candidates for a free vertex; requiring it of both side pairs picks the
+/// parallelogram.
+#[derive(Debug, Clone)]
+pub struct ParallelogramConstraint {
+    /// Vertices in order around the quadrilateral
+    pub vertices: [PointId; 4],
+}
+
+impl ParallelogramConstraint {
+    /// Create a new parallelogram constraint
+    pub fn new(vertices: [PointId; 4]) -> Self {
+        Self { vertices }
+    }
+}
+
+impl Constraint for ParallelogramConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let vars = self
+            .vertices
+            .iter()
+            .map(|&p| {
+                sketch
+                    .point_variables(p)
+                    .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", p)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Direction of side i, from vertex i to vertex i+1
+        let side = |i: usize| {
+            let (x1, y1) = &vars[i];
+            let (x2, y2) = &vars[(i + 1) % 4];
+            (x2.sub(x1), y2.sub(y1))
+        };
+
+        for i in 0..2 {
+            let (ux, uy) = side(i);
+            let (vx, vy) = side(i + 2);
+
+            // Parallel: u × v = 0
+            let cross = (&ux).mul(&vy).sub(&(&uy).mul(&vx));
+            solver.assert(&cross._eq(&Real::from_real(context, 0, 1)));
+
+            // Equal length: |u|² = |v|²
+            let u_sq = (&ux).mul(&ux).add(&(&uy).mul(&uy));
+            let v_sq = (&vx).mul(&vx).add(&(&vy).mul(&vy));
+            solver.assert(&u_sq._eq(&v_sq));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Points {:?} form a parallelogram", self.vertices)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_parallelogram(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.vertices
+            .iter()
+            .copied()
+            .map(EntityRef::Point)
+            .collect()
+    }
+}
+
+/// Constraint that the two diagonals of a quadrilateral have equal length
+///
+/// For vertices `v0..v3` in order, asserts `|v2 - v0|² = |v3 - v1|²`. A
+/// parallelogram with equal diagonals is a rectangle.
+#[derive(Debug, Clone)]
+pub struct EqualDiagonalsConstraint {
+    /// Vertices in order around the quadrilateral
+    pub vertices: [PointId; 4],
+}
+
+impl EqualDiagonalsConstraint {
+    /// Create a new equal diagonals constraint
+    pub fn new(vertices: [PointId; 4]) -> Self {
+        Self { vertices }
+    }
+}
+
+impl Constraint for EqualDiagonalsConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let vars = self
+            .vertices
+            .iter()
+            .map(|&p| {
+                sketch
+                    .point_variables(p)
+                    .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", p)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Squared length of the diagonal from vertex i to vertex i+2
+        let diagonal_sq = |i: usize| {
+            let (x1, y1) = &vars[i];
+            let (x2, y2) = &vars[i + 2];
+            let dx = x2.sub(x1);
+            let dy = y2.sub(y1);
+            (&dx).mul(&dx).add(&(&dy).mul(&dy))
+        };
+
+        solver.assert(&diagonal_sq(0)._eq(&diagonal_sq(1)));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Points {:?} have equal diagonals", self.vertices)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_equal_diagonals(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.vertices
+            .iter()
+            .copied()
+            .map(EntityRef::Point)
+            .collect()
+    }
+}
+
+/// Constraint that four points form a rhombus
+///
+/// For vertices `v0..v3` in order, the edges `v0→v1→v2→v3→v0` close the loop
+/// by construction, and each edge's squared length is asserted equal to the
+/// first. Equal sides alone also admit degenerate shapes where two vertices
+/// coincide; combine with `ConvexPolygonConstraint` to rule them out.
+#[derive(Debug, Clone)]
+pub struct RhombusConstraint {
+    /// Vertices in order around the rhombus
+    pub vertices: [PointId; 4],
+}
+
+impl RhombusConstraint {
+    /// Create a new rhombus constraint
+    pub fn new(vertices: [PointId; 4]) -> Self {
+        Self { vertices }
+    }
+}
+
+impl Constraint for RhombusConstraint {
+    fn apply(
+        &self,
+        _context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let vars = self
+            .vertices
+            .iter()
+            .map(|&p| {
+                sketch
+                    .point_variables(p)
+                    .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", p)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Squared length of the edge from vertex i to the next, wrapping around
+        let edge_sq = |i: usize| {
+            let (x1, y1) = &vars[i];
+            let (x2, y2) = &vars[(i + 1) % 4];
+            let dx = x2.sub(x1);
+            let dy = y2.sub(y1);
+            (&dx).mul(&dx).add(&(&dy).mul(&dy))
+        };
+
+        let first = edge_sq(0);
+        for i in 1..4 {
+            solver.assert(&edge_sq(i)._eq(&first));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Points {:?} form a rhombus", self.vertices)
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_rhombus(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        self.vertices
+            .iter()
+            .copied()
+            .map(EntityRef::Point)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generational_arena::Index;
+    use std::collections::HashMap;
+    use z3::ast::Real;
+    use z3::{Config, Context, SatResult, Solver};
+
+    // Mock implementation of SketchQuery for testing polygon constraints
+    struct MockPolygonSketch<'ctx> {
+        points: HashMap<PointId, (Real<'ctx>, Real<'ctx>)>,
+        lines: HashMap<LineId, (PointId, PointId)>,
+    }
+
+    impl<'ctx> MockPolygonSketch<'ctx> {
+        fn new() -> Self {
+            Self {
+                points: HashMap::new(),
+                lines: HashMap::new(),
+            }
+        }
+
+        fn add_point(&mut self, id: PointId, x: Real<'ctx>, y: Real<'ctx>) {
+            self.points.insert(id, (x, y));
+        }
+
+        fn add_line(&mut self, line_id: LineId, start: PointId, end: PointId) {
+            self.lines.insert(line_id, (start, end));
+        }
+    }
+
+    impl<'ctx> SketchQuery for MockPolygonSketch<'ctx> {
+        fn point_variables(&self, point_id: PointId) -> Result<(Real<'_>, Real<'_>)> {
+            self.points
+                .get(&point_id)
+                .map(|(x, y)| (x.clone(), y.clone()))
+                .ok_or_else(|| TextCadError::EntityError("Point not found".to_string()))
+        }
+
+        fn line_endpoints(&self, line_id: LineId) -> Result<(PointId, PointId)> {
+            self.lines
+                .get(&line_id)
+                .copied()
+                .ok_or_else(|| TextCadError::EntityError("Line not found".to_string()))
+        }
+
+        fn circle_center_and_radius(
+            &self,
+            _circle_id: crate::entity::CircleId,
+        ) -> Result<(PointId, Real<'_>)> {
+            Err(TextCadError::InvalidConstraint(
+                "Not implemented".to_string(),
+            ))
+        }
+
+        fn length_variable(&self, _name: &str) -> Result<Real<'_>> {
+            Err(TextCadError::InvalidConstraint(
+                "Not implemented".to_string(),
+            ))
+        }
+
+        fn angle_variable(&self, _name: &str) -> Result<Real<'_>> {
+            Err(TextCadError::InvalidConstraint(
+                "Not implemented".to_string(),
+            ))
+        }
+    }
+
+    fn point(i: usize) -> PointId {
+        PointId(Index::from_raw_parts(i, 0))
+    }
+
+    fn line(i: usize) -> LineId {
+        LineId(Index::from_raw_parts(i, 0))
+    }
+
+    #[test]
+    fn test_closed_loop_constraint_creation() {
+        let constraint = ClosedLoopConstraint::new(vec![line(0), line(1), line(2)]);
+
+        assert_eq!(constraint.lines.len(), 3);
+        assert!(constraint.description().contains("closed loop"));
+    }
+
+    #[test]
+    fn test_closed_loop_constraint_apply_independent_endpoints() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockPolygonSketch::new();
+        for i in 0..6 {
+            mock_sketch.add_point(
+                point(i),
+                Real::new_const(&ctx, format!("x{}", i)),
+                Real::new_const(&ctx, format!("y{}", i)),
+            );
+        }
+        for i in 0..3 {
+            mock_sketch.add_line(line(i), point(2 * i), point(2 * i + 1));
+        }
+
+        let constraint = ClosedLoopConstraint::new(vec![line(0), line(1), line(2)]);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        // Three joints, each with x and y equalities
+        assert_eq!(solver.get_assertions().len(), 6);
+    }
+
+    #[test]
+    fn test_closed_loop_constraint_skips_shared_points() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockPolygonSketch::new();
+        for i in 0..3 {
+            mock_sketch.add_point(
+                point(i),
+                Real::new_const(&ctx, format!("x{}", i)),
+                Real::new_const(&ctx, format!("y{}", i)),
+            );
+        }
+        mock_sketch.add_line(line(0), point(0), point(1));
+        mock_sketch.add_line(line(1), point(1), point(2));
+        mock_sketch.add_line(line(2), point(2), point(0));
+
+        let constraint = ClosedLoopConstraint::new(vec![line(0), line(1), line(2)]);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.get_assertions().len(), 0);
+    }
+
+    #[test]
+    fn test_closed_loop_constraint_too_few_lines() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mock_sketch = MockPolygonSketch::new();
+        let constraint = ClosedLoopConstraint::new(vec![line(0), line(1)]);
+
+        let result = constraint.apply(&ctx, &solver, &mock_sketch);
+        assert!(matches!(result, Err(TextCadError::InvalidConstraint(_))));
+    }
+
+    #[test]
+    fn test_min_area_constraint_apply() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let mut mock_sketch = MockPolygonSketch::new();
+        for i in 0..4 {
+            mock_sketch.add_point(
+                point(i),
+                Real::new_const(&ctx, format!("x{}", i)),
+                Real::new_const(&ctx, format!("y{}", i)),
+            );
+        }
+
+        let constraint = MinAreaConstraint::new(
+            vec![point(0), point(1), point(2), point(3)],
+            Area::square_meters(2.0),
+        );
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.get_assertions().len(), 1);
+        assert!(constraint.description().contains("at least"));
+    }
+
+    #[test]
+    fn test_min_area_constraint_invalid_parameters() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+        let mock_sketch = MockPolygonSketch::new();
+
+        let too_few = MinAreaConstraint::new(vec![point(0), point(1)], Area::square_meters(1.0));
+        assert!(matches!(
+            too_few.apply(&ctx, &solver, &mock_sketch),
+            Err(TextCadError::InvalidConstraint(_))
+        ));
+
+        let negative = MinAreaConstraint::new(
+            vec![point(0), point(1), point(2)],
+            Area::square_meters(-1.0),
+        );
+        assert!(matches!(
+            negative.apply(&ctx, &solver, &mock_sketch),
+            Err(TextCadError::InvalidConstraint(_))
+        ));
+    }
+
+    fn fixed_polygon<'ctx>(
+        ctx: &'ctx Context,
+        solver: &Solver<'ctx>,
+        coords: &[(i32, i32)],
+    ) -> (MockPolygonSketch<'ctx>, Vec<PointId>) {
+        let mut mock_sketch = MockPolygonSketch::new();
+        let ids = coords
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| {
+                let px = Real::new_const(ctx, format!("x{}", i));
+                let py = Real::new_const(ctx, format!("y{}", i));
+                solver.assert(&px._eq(&Real::from_real(ctx, x, 1)));
+                solver.assert(&py._eq(&Real::from_real(ctx, y, 1)));
+                mock_sketch.add_point(point(i), px, py);
+                point(i)
+            })
+            .collect();
+        (mock_sketch, ids)
+    }
+
+    #[test]
+    fn test_convex_polygon_constraint_convex_quad() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let (mock_sketch, vertices) =
+            fixed_polygon(&ctx, &solver, &[(0, 0), (4, 0), (5, 3), (1, 4)]);
+
+        let constraint = ConvexPolygonConstraint::new(vertices);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Sat);
+    }
+
+    #[test]
+    fn test_convex_polygon_constraint_concave_quad() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        // Arrowhead: the vertex at (2, 1) points inward
+        let (mock_sketch, vertices) =
+            fixed_polygon(&ctx, &solver, &[(0, 0), (2, 1), (4, 0), (2, 4)]);
+
+        let constraint = ConvexPolygonConstraint::new(vertices);
+        constraint.apply(&ctx, &solver, &mock_sketch).unwrap();
+
+        assert_eq!(solver.check(), SatResult::Unsat);
+    }
+}
@@ -579,7 +579,9 @@ mod tests {
                 prop_assert!(radius_var_str.contains(&expected_radius_name));
             } else {
                 prop_assert!(circle.display_name().starts_with("Circle"));
-                prop_assert!(radius_var_str.contains("c_radius")); // Default naming
+                let (index, generation) = circle_id.0.into_raw_parts();
+                let expected_radius_name = format!("c{}_{}_radius", index, generation);
+                prop_assert!(radius_var_str.contains(&expected_radius_name)); // Default naming
             }
 
             // Display name should be consistent across multiple calls
@@ -0,0 +1,243 @@
+//! Symmetry constraints for geometric modeling
+//!
+//! Implements mirror symmetry of points and lines across an axis line, and
+//! radial symmetry of points about a center.
+
+use crate::constraint::{Constraint, ConstraintVisitor, EntityRef, SketchQuery};
+use crate::entities::PointId;
+use crate::entity::LineId;
+use crate::error::{Result, TextCadError};
+use crate::units::Length;
+use std::f64::consts::TAU;
+use std::ops::{Add, Mul, Sub};
+use z3::ast::{Ast, Real};
+
+/// Constraint that makes two points mirror images across an axis line
+///
+/// With the axis running from P1 in direction `d`, asserts that the midpoint
+/// of the pair lies on the axis (`d × (a + b - 2·P1) = 0`) and that the pair
+/// is perpendicular to it (`(b - a) · d = 0`).
+#[derive(Debug, Clone)]
+pub struct SymmetryConstraint {
+    /// Mirror axis
+    pub axis: LineId,
+    /// First point of the pair
+    pub a: PointId,
+    /// Second point of the pair
+    pub b: PointId,
+}
+
+impl SymmetryConstraint {
+    /// Create a new point symmetry constraint
+    pub fn new(axis: LineId, a: PointId, b: PointId) -> Self {
+        Self { axis, a, b }
+    }
+}
+
+impl Constraint for SymmetryConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (start_id, end_id) = sketch
+            .line_endpoints(self.axis)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.axis)))?;
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (x1, y1) = point(start_id)?;
+        let (x2, y2) = point(end_id)?;
+        let (ax, ay) = point(self.a)?;
+        let (bx, by) = point(self.b)?;
+
+        let dx = (&x2).sub(&x1);
+        let dy = (&y2).sub(&y1);
+        let zero = Real::from_real(context, 0, 1);
+        let two = Real::from_real(context, 2, 1);
+
+        // d × (a + b - 2·P1) = 0
+        let mx = (&ax).add(&bx).sub(&(&two).mul(&x1));
+        let my = (&ay).add(&by).sub(&(&two).mul(&y1));
+        let cross = (&dx).mul(&my).sub(&(&dy).mul(&mx));
+        solver.assert(&cross._eq(&zero));
+
+        // (b - a) · d = 0
+        let dot = (&bx).sub(&ax).mul(&dx).add(&(&by).sub(&ay).mul(&dy));
+        solver.assert(&dot._eq(&zero));
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Points {:?} and {:?} are mirrored across line {:?}",
+            self.a, self.b, self.axis
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_symmetry(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Line(self.axis),
+            EntityRef::Point(self.a),
+            EntityRef::Point(self.b),
+        ]
+    }
+}
+
+/// Constraint that makes one line the mirror image of another across an axis
+///
+/// Applies `SymmetryConstraint` to the start points and to the end points of
+/// `line_a` and `line_b`.
+#[derive(Debug, Clone)]
+pub struct LineSymmetryConstraint {
+    /// Mirror axis
+    pub axis: LineId,
+    /// Line being mirrored
+    pub line_a: LineId,
+    /// Mirror image of `line_a`
+    pub line_b: LineId,
+}
+
+impl LineSymmetryConstraint {
+    /// Create a new line symmetry constraint
+    pub fn new(axis: LineId, line_a: LineId, line_b: LineId) -> Self {
+        Self {
+            axis,
+            line_a,
+            line_b,
+        }
+    }
+}
+
+impl Constraint for LineSymmetryConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        let (a_start, a_end) = sketch
+            .line_endpoints(self.line_a)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line_a)))?;
+        let (b_start, b_end) = sketch
+            .line_endpoints(self.line_b)
+            .map_err(|_| TextCadError::EntityError(format!("Line {:?} not found", self.line_b)))?;
+
+        SymmetryConstraint::new(self.axis, a_start, b_start).apply(context, solver, sketch)?;
+        SymmetryConstraint::new(self.axis, a_end, b_end).apply(context, solver, sketch)
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Line {:?} mirrors line {:?} across line {:?}",
+            self.line_b, self.line_a, self.axis
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_line_symmetry(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        vec![
+            EntityRef::Line(self.axis),
+            EntityRef::Line(self.line_a),
+            EntityRef::Line(self.line_b),
+        ]
+    }
+}
+
+/// Constraint that spaces points evenly on a circle about a center
+///
+/// With `N` points, point `i` is placed at angle `2π·i/N` from the positive
+/// x-axis and distance `radius` from the center. The offsets are constants,
+/// so every assertion is linear in the point coordinates.
+#[derive(Debug, Clone)]
+pub struct RadialSymmetryConstraint {
+    /// Center of the pattern
+    pub center: PointId,
+    /// Points in counter-clockwise order, starting at angle zero
+    pub points: Vec<PointId>,
+    /// Distance from the center to every point
+    pub radius: Length,
+}
+
+impl RadialSymmetryConstraint {
+    /// Create a new radial symmetry constraint
+    pub fn new(center: PointId, points: Vec<PointId>, radius: Length) -> Self {
+        Self {
+            center,
+            points,
+            radius,
+        }
+    }
+}
+
+impl Constraint for RadialSymmetryConstraint {
+    fn apply(
+        &self,
+        context: &z3::Context,
+        solver: &z3::Solver,
+        sketch: &dyn SketchQuery,
+    ) -> Result<()> {
+        if self.points.is_empty() {
+            return Err(TextCadError::InvalidConstraint(
+                "Radial symmetry requires at least one point".to_string(),
+            ));
+        }
+
+        let point = |id: PointId| {
+            sketch
+                .point_variables(id)
+                .map_err(|_| TextCadError::EntityError(format!("Point {:?} not found", id)))
+        };
+        let (cx, cy) = point(self.center)?;
+
+        let radius = self.radius.to_meters();
+        let count = self.points.len() as f64;
+        for (i, &id) in self.points.iter().enumerate() {
+            let (px, py) = point(id)?;
+            let angle = TAU * i as f64 / count;
+            let offset_x = crate::constraints::real_from_f64(context, radius * angle.cos())?;
+            let offset_y = crate::constraints::real_from_f64(context, radius * angle.sin())?;
+
+            solver.assert(&px._eq(&(&cx).add(&offset_x)));
+            solver.assert(&py._eq(&(&cy).add(&offset_y)));
+        }
+
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} points evenly spaced around {:?} at radius {:.3}m",
+            self.points.len(),
+            self.center,
+            self.radius.to_meters()
+        )
+    }
+
+    fn accept(&self, visitor: &mut dyn ConstraintVisitor) {
+        visitor.visit_radial_symmetry(self);
+    }
+
+    fn referenced_entities(&self) -> Vec<EntityRef> {
+        std::iter::once(self.center)
+            .chain(self.points.iter().copied())
+            .map(EntityRef::Point)
+            .collect()
+    }
+
+    fn is_linear(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,129 @@
+//! Arc entity implementation
+//!
+//! Provides Arc structure with Z3 integration. Arcs are composite entities that
+//! reference a Circle plus start and end PointIds, and carry start/end angle
+//! values as Z3 symbolic variables.
+
+use crate::entities::PointId;
+use crate::entity::{ArcId, CircleId};
+use z3::{Context, ast::Real};
+
+/// Circular arc running counter-clockwise from a start point to an end point
+///
+/// The arc lies on a referenced circle. Its start and end angles (radians,
+/// measured from the positive x-axis) are free Z3 variables; constraints
+/// relate them to each other, since tying them to the endpoint coordinates
+/// would require transcendental functions.
+#[derive(Debug, Clone)]
+pub struct Arc<'ctx> {
+    /// Unique identifier for this arc
+    pub id: ArcId,
+    /// Circle the arc is a portion of
+    pub circle: CircleId,
+    /// Start point of the arc
+    pub start: PointId,
+    /// End point of the arc
+    pub end: PointId,
+    /// Start angle as Z3 Real variable (radians)
+    pub start_angle: Real<'ctx>,
+    /// End angle as Z3 Real variable (radians)
+    pub end_angle: Real<'ctx>,
+    /// Optional name for debugging and display
+    pub name: Option<String>,
+}
+
+impl<'ctx> Arc<'ctx> {
+    /// Create a new Arc on a circle between two points
+    ///
+    /// # Arguments
+    /// * `id` - Unique identifier for this arc
+    /// * `circle` - CircleId of the supporting circle
+    /// * `start` - PointId of the start point
+    /// * `end` - PointId of the end point
+    /// * `ctx` - Z3 context for creating symbolic variables
+    /// * `name` - Optional name for debugging (affects Z3 variable names).
+    ///   Unnamed entities get variables named after their arena index.
+    pub fn new(
+        id: ArcId,
+        circle: CircleId,
+        start: PointId,
+        end: PointId,
+        ctx: &'ctx Context,
+        name: Option<String>,
+    ) -> Self {
+        let base_name = match &name {
+            Some(name) => name.clone(),
+            None => {
+                let (index, generation) = id.0.into_raw_parts();
+                format!("a{}_{}", index, generation)
+            }
+        };
+        let start_angle = Real::new_const(ctx, format!("{}_start_angle", base_name));
+        let end_angle = Real::new_const(ctx, format!("{}_end_angle", base_name));
+
+        Self {
+            id,
+            circle,
+            start,
+            end,
+            start_angle,
+            end_angle,
+            name,
+        }
+    }
+
+    /// Get the arc's name, or a default if none was specified
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("Arc{:?}", self.id.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generational_arena::Index;
+    use z3::{Config, Context};
+
+    #[test]
+    fn test_arc_creation_with_name() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let arc_id = ArcId::from(Index::from_raw_parts(0, 0));
+        let circle_id = CircleId::from(Index::from_raw_parts(0, 0));
+        let start = PointId::from(Index::from_raw_parts(1, 0));
+        let end = PointId::from(Index::from_raw_parts(2, 0));
+
+        let arc = Arc::new(
+            arc_id,
+            circle_id,
+            start,
+            end,
+            &ctx,
+            Some("fillet".to_string()),
+        );
+
+        assert_eq!(arc.id, arc_id);
+        assert_eq!(arc.circle, circle_id);
+        assert_eq!(arc.start, start);
+        assert_eq!(arc.end, end);
+        assert_eq!(arc.display_name(), "fillet");
+        assert!(arc.start_angle.to_string().contains("fillet_start_angle"));
+        assert!(arc.end_angle.to_string().contains("fillet_end_angle"));
+    }
+
+    #[test]
+    fn test_arc_creation_without_name() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let arc_id = ArcId::from(Index::from_raw_parts(3, 0));
+        let circle_id = CircleId::from(Index::from_raw_parts(0, 0));
+        let point = PointId::from(Index::from_raw_parts(0, 0));
+
+        let arc = Arc::new(arc_id, circle_id, point, point, &ctx, None);
+
+        assert!(arc.display_name().starts_with("Arc"));
+        assert!(arc.start_angle.to_string().contains("a3_0_start_angle"));
+    }
+}
@@ -3,7 +3,7 @@
 //! Provides Circle structure with Z3 integration for constraint-based 2D CAD modeling.
 //! Circles are composite entities defined by a center PointId and a radius as a Z3 symbolic variable.
 
-use crate::entities::PointId;
+use crate::entities::{EntityMetadata, PointId};
 use crate::entity::CircleId;
 use z3::{Context, ast::Real};
 
@@ -13,7 +13,7 @@ use z3::{Context, ast::Real};
 /// and stores its radius as a Z3 symbolic variable. This design supports the
 /// constraint-based modeling approach where relationships between entities are
 /// more important than concrete parameter values.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Circle<'ctx> {
     /// Unique identifier for this circle
     pub id: CircleId,
@@ -23,6 +23,8 @@ pub struct Circle<'ctx> {
     pub radius: Real<'ctx>,
     /// Optional name for debugging and display
     pub name: Option<String>,
+    /// Layer and color tags read by exporters
+    pub metadata: EntityMetadata,
 }
 
 impl<'ctx> Circle<'ctx> {
@@ -32,7 +34,8 @@ impl<'ctx> Circle<'ctx> {
     /// * `id` - Unique identifier for this circle
     /// * `center` - PointId of the center point
     /// * `ctx` - Z3 context for creating symbolic variables
-    /// * `name` - Optional name for debugging (affects Z3 variable names)
+    /// * `name` - Optional name for debugging (affects Z3 variable names).
+    ///   Unnamed entities get variables named after their arena index.
     ///
     /// # Example
     /// ```
@@ -48,7 +51,13 @@ impl<'ctx> Circle<'ctx> {
     /// let circle = Circle::new(circle_id, center_id, &ctx, Some("c1".to_string()));
     /// ```
     pub fn new(id: CircleId, center: PointId, ctx: &'ctx Context, name: Option<String>) -> Self {
-        let base_name = name.as_deref().unwrap_or("c");
+        let base_name = match &name {
+            Some(name) => name.clone(),
+            None => {
+                let (index, generation) = id.0.into_raw_parts();
+                format!("c{}_{}", index, generation)
+            }
+        };
         let radius = Real::new_const(ctx, format!("{}_radius", base_name));
 
         Self {
@@ -56,6 +65,7 @@ impl<'ctx> Circle<'ctx> {
             center,
             radius,
             name,
+            metadata: EntityMetadata::default(),
         }
     }
 
@@ -113,8 +123,8 @@ mod tests {
         assert_eq!(circle.name, None);
         assert!(circle.display_name().starts_with("Circle"));
 
-        // Verify Z3 variable has default name
-        assert!(circle.radius.to_string().contains("c_radius"));
+        // Verify Z3 variable is named after the arena index
+        assert!(circle.radius.to_string().contains("c1_0_radius"));
     }
 
     #[test]
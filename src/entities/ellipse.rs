@@ -0,0 +1,100 @@
+//! Ellipse entity implementation
+//!
+//! Provides an axis-aligned Ellipse structure with Z3 integration. Ellipses are
+//! composite entities defined by a center PointId and two semi-axis lengths as
+//! Z3 symbolic variables.
+
+use crate::entities::PointId;
+use crate::entity::EllipseId;
+use z3::{Context, ast::Real};
+
+/// Axis-aligned 2D ellipse defined by a center point and two semi-axes
+///
+/// The semi-major axis runs along the x-axis and the semi-minor axis along
+/// the y-axis. Both are free Z3 variables until constrained.
+#[derive(Debug, Clone)]
+pub struct Ellipse<'ctx> {
+    /// Unique identifier for this ellipse
+    pub id: EllipseId,
+    /// Center point of the ellipse
+    pub center: PointId,
+    /// Semi-major axis (along x) as Z3 Real variable
+    pub semi_major: Real<'ctx>,
+    /// Semi-minor axis (along y) as Z3 Real variable
+    pub semi_minor: Real<'ctx>,
+    /// Optional name for debugging and display
+    pub name: Option<String>,
+}
+
+impl<'ctx> Ellipse<'ctx> {
+    /// Create a new Ellipse with a center point and symbolic semi-axes
+    ///
+    /// # Arguments
+    /// * `id` - Unique identifier for this ellipse
+    /// * `center` - PointId of the center point
+    /// * `ctx` - Z3 context for creating symbolic variables
+    /// * `name` - Optional name for debugging (affects Z3 variable names).
+    ///   Unnamed entities get variables named after their arena index.
+    pub fn new(id: EllipseId, center: PointId, ctx: &'ctx Context, name: Option<String>) -> Self {
+        let base_name = match &name {
+            Some(name) => name.clone(),
+            None => {
+                let (index, generation) = id.0.into_raw_parts();
+                format!("e{}_{}", index, generation)
+            }
+        };
+        let semi_major = Real::new_const(ctx, format!("{}_semi_major", base_name));
+        let semi_minor = Real::new_const(ctx, format!("{}_semi_minor", base_name));
+
+        Self {
+            id,
+            center,
+            semi_major,
+            semi_minor,
+            name,
+        }
+    }
+
+    /// Get the ellipse's name, or a default if none was specified
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("Ellipse{:?}", self.id.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generational_arena::Index;
+    use z3::{Config, Context};
+
+    #[test]
+    fn test_ellipse_creation_with_name() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let ellipse_id = EllipseId::from(Index::from_raw_parts(0, 0));
+        let center_id = PointId::from(Index::from_raw_parts(0, 0));
+
+        let ellipse = Ellipse::new(ellipse_id, center_id, &ctx, Some("oval".to_string()));
+
+        assert_eq!(ellipse.id, ellipse_id);
+        assert_eq!(ellipse.center, center_id);
+        assert_eq!(ellipse.display_name(), "oval");
+        assert!(ellipse.semi_major.to_string().contains("oval_semi_major"));
+        assert!(ellipse.semi_minor.to_string().contains("oval_semi_minor"));
+    }
+
+    #[test]
+    fn test_ellipse_creation_without_name() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let ellipse_id = EllipseId::from(Index::from_raw_parts(1, 0));
+        let center_id = PointId::from(Index::from_raw_parts(2, 0));
+
+        let ellipse = Ellipse::new(ellipse_id, center_id, &ctx, None);
+
+        assert!(ellipse.display_name().starts_with("Ellipse"));
+        assert!(ellipse.semi_major.to_string().contains("e1_0_semi_major"));
+    }
+}
@@ -7,7 +7,7 @@ use crate::constraints::{
     LineLengthConstraint, ParallelLinesConstraint, PerpendicularLinesConstraint,
     PointOnLineConstraint,
 };
-use crate::entities::PointId;
+use crate::entities::{EntityMetadata, PointId};
 use crate::entity::LineId;
 use crate::units::Length;
 
@@ -27,6 +27,8 @@ pub struct Line {
     pub end: PointId,
     /// Optional name for debugging and display
     pub name: Option<String>,
+    /// Layer and color tags read by exporters
+    pub metadata: EntityMetadata,
 }
 
 impl Line {
@@ -58,6 +60,7 @@ impl Line {
             start,
             end,
             name,
+            metadata: EntityMetadata::default(),
         }
     }
 
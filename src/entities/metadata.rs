@@ -0,0 +1,43 @@
+//! Presentation metadata for entities
+//!
+//! Metadata does not take part in solving; exporters read it to place
+//! entities on layers and style them.
+
+/// Optional layer and color tags attached to a line or circle
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntityMetadata {
+    /// Layer name, e.g. `"cut"` or `"engrave"`
+    pub layer: Option<String>,
+    /// Color in the target format's syntax, e.g. `"red"` or `"#ff0000"` for SVG
+    pub color: Option<String>,
+}
+
+impl EntityMetadata {
+    /// Create metadata with only a layer set
+    pub fn layer(layer: impl Into<String>) -> Self {
+        Self {
+            layer: Some(layer.into()),
+            color: None,
+        }
+    }
+
+    /// Set the color, keeping the layer
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_builders() {
+        let metadata = EntityMetadata::layer("cut").with_color("red");
+
+        assert_eq!(metadata.layer.as_deref(), Some("cut"));
+        assert_eq!(metadata.color.as_deref(), Some("red"));
+        assert_eq!(EntityMetadata::default().layer, None);
+    }
+}
@@ -1,12 +1,18 @@
 //! Geometric entity implementations
 //!
-//! This module contains implementations of geometric entities (Point2D, Line, Circle)
+//! This module contains implementations of geometric entities (Point2D, Line, Circle, Arc, Ellipse)
 //! that integrate with Z3 for constraint-based modeling.
 
+pub mod arc;
 pub mod circle;
+pub mod ellipse;
 pub mod line;
+pub mod metadata;
 pub mod point;
 
+pub use arc::Arc;
 pub use circle::Circle;
+pub use ellipse::Ellipse;
 pub use line::Line;
+pub use metadata::EntityMetadata;
 pub use point::{Point2D, PointId};
@@ -26,7 +26,7 @@ impl From<PointId> for Index {
 /// Point2D provides the foundation for geometric constraint modeling by
 /// representing point coordinates as Z3 symbolic variables rather than
 /// concrete values.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Point2D<'ctx> {
     /// Unique identifier for this point
     pub id: PointId,
@@ -44,7 +44,9 @@ impl<'ctx> Point2D<'ctx> {
     /// # Arguments
     /// * `id` - Unique identifier for this point
     /// * `ctx` - Z3 context for creating symbolic variables
-    /// * `name` - Optional name for debugging (affects Z3 variable names)
+    /// * `name` - Optional name for debugging (affects Z3 variable names).
+    ///   Unnamed points get variables named after their arena index, e.g.
+    ///   `p3_0_x`, so distinct unnamed points never share variables.
     ///
     /// # Example
     /// ```
@@ -58,7 +60,13 @@ impl<'ctx> Point2D<'ctx> {
     /// let point = Point2D::new(id, &ctx, Some("p1".to_string()));
     /// ```
     pub fn new(id: PointId, ctx: &'ctx Context, name: Option<String>) -> Self {
-        let base_name = name.as_deref().unwrap_or("p");
+        let base_name = match &name {
+            Some(name) => name.clone(),
+            None => {
+                let (index, generation) = id.0.into_raw_parts();
+                format!("p{}_{}", index, generation)
+            }
+        };
         let x = Real::new_const(ctx, format!("{}_x", base_name));
         let y = Real::new_const(ctx, format!("{}_y", base_name));
 
@@ -71,6 +79,16 @@ impl<'ctx> Point2D<'ctx> {
             .clone()
             .unwrap_or_else(|| format!("Point{:?}", self.id.0))
     }
+
+    /// Get the Z3 variables for the point's x and y coordinates
+    ///
+    /// This is the supported way for code outside the crate to build custom
+    /// assertions over a point, e.g. in a `Constraint` implementation or via
+    /// `Sketch::solver_mut`. Prefer it over accessing the `x`/`y` fields
+    /// directly, whose representation may change.
+    pub fn variables(&self) -> (&Real<'ctx>, &Real<'ctx>) {
+        (&self.x, &self.y)
+    }
 }
 
 #[cfg(test)]
@@ -117,9 +135,9 @@ mod tests {
         assert_eq!(point.name, None);
         assert!(point.display_name().starts_with("Point"));
 
-        // Verify Z3 variables have default names
-        assert!(point.x.to_string().contains("p_x"));
-        assert!(point.y.to_string().contains("p_y"));
+        // Verify Z3 variables are named after the arena index
+        assert!(point.x.to_string().contains("p1_0_x"));
+        assert!(point.y.to_string().contains("p1_0_y"));
     }
 
     #[test]
@@ -144,6 +162,35 @@ mod tests {
         assert!(point2.x.to_string().contains("p2_x"));
     }
 
+    #[test]
+    fn test_variables_in_custom_assertion() {
+        use z3::ast::Ast;
+        use z3::{SatResult, Solver};
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let id = PointId::from(Index::from_raw_parts(0, 0));
+        let point = Point2D::new(id, &ctx, Some("custom".to_string()));
+
+        // Custom constraint: the point lies on the line y = 2x + 1 with x = 3
+        let (x, y) = point.variables();
+        let solver = Solver::new(&ctx);
+        solver.assert(&x._eq(&Real::from_real(&ctx, 3, 1)));
+        let two_x_plus_one = Real::add(
+            &ctx,
+            &[
+                &Real::mul(&ctx, &[&Real::from_real(&ctx, 2, 1), x]),
+                &Real::from_real(&ctx, 1, 1),
+            ],
+        );
+        solver.assert(&y._eq(&two_x_plus_one));
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let y_value = model.eval(y, true).unwrap().as_real().unwrap();
+        assert_eq!(y_value, (7, 1));
+    }
+
     #[test]
     fn test_point_id_ordering() {
         let id1 = PointId::from(Index::from_raw_parts(0, 0));
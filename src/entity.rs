@@ -32,6 +32,38 @@ impl From<CircleId> for Index {
     }
 }
 
+/// Strongly-typed identifier for Arc entities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArcId(pub Index);
+
+impl From<Index> for ArcId {
+    fn from(index: Index) -> Self {
+        ArcId(index)
+    }
+}
+
+impl From<ArcId> for Index {
+    fn from(id: ArcId) -> Self {
+        id.0
+    }
+}
+
+/// Strongly-typed identifier for Ellipse entities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EllipseId(pub Index);
+
+impl From<Index> for EllipseId {
+    fn from(index: Index) -> Self {
+        EllipseId(index)
+    }
+}
+
+impl From<EllipseId> for Index {
+    fn from(id: EllipseId) -> Self {
+        id.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,5 +99,7 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<LineId>();
         assert_send_sync::<CircleId>();
+        assert_send_sync::<ArcId>();
+        assert_send_sync::<EllipseId>();
     }
 }
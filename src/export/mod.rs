@@ -0,0 +1,23 @@
+//! Export of solved sketches to external formats
+//!
+//! Exporters turn a sketch together with its extracted solution into a
+//! textual representation such as SVG or CSV.
+
+pub mod csv;
+pub mod svg;
+
+pub use csv::CSVExporter;
+pub use svg::SVGExporter;
+
+use crate::error::Result;
+use crate::sketch::Sketch;
+use crate::solution::Solution;
+
+/// Trait for converting a solved sketch into an output format
+pub trait Exporter {
+    /// Export the sketch using the concrete values from `solution`
+    ///
+    /// The solution must have been produced by `Sketch::solve_and_extract`
+    /// so that all line and circle parameters are available.
+    fn export(&self, sketch: &Sketch, solution: &Solution) -> Result<String>;
+}
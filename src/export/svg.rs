@@ -0,0 +1,371 @@
+//! SVG export for solved sketches
+//!
+//! Converts sketch coordinates (meters, y up) into SVG user units (y down).
+
+use crate::constraint::EntityRef;
+use crate::entities::EntityMetadata;
+use crate::error::{Result, TextCadError};
+use crate::export::Exporter;
+use crate::sketch::Sketch;
+use crate::solution::Solution;
+use crate::units::Length;
+use std::collections::BTreeMap;
+
+/// Exporter that renders lines and circles as an SVG document
+///
+/// Output is deterministic: all lines are emitted before all circles, and
+/// within each group entities are ordered by their raw arena index (then
+/// generation), independent of arena iteration order.
+///
+/// Each element's `class` is the entity's metadata layer and its `stroke`
+/// the metadata color (black when unset), see `EntityMetadata`.
+#[derive(Debug, Clone)]
+pub struct SVGExporter {
+    /// Meters to SVG units (default: 1m = 1000 units)
+    scale: f64,
+    /// Stroke width in SVG units
+    stroke_width: f64,
+    /// Padding around the bounding box in SVG units
+    view_box_padding: f64,
+    /// Affine transform `[a, b, c, d, e, f]` applied in sketch space
+    transform: Option<[f64; 6]>,
+    /// Per-entity translations `(dx, dy)` in meters, applied only at export
+    instance_offsets: BTreeMap<EntityRef, (f64, f64)>,
+    /// Spacing of the background reference grid, if enabled
+    grid: Option<Length>,
+    /// Decimal places for coordinates and lengths (default: 2)
+    precision: usize,
+}
+
+impl Default for SVGExporter {
+    fn default() -> Self {
+        Self {
+            scale: 1000.0, // 1 meter = 1000 SVG units (mm)
+            stroke_width: 2.0,
+            view_box_padding: 10.0,
+            transform: None,
+            instance_offsets: BTreeMap::new(),
+            grid: None,
+            precision: 2,
+        }
+    }
+}
+
+impl SVGExporter {
+    /// Create an exporter with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a 2D affine transform to every coordinate before export
+    ///
+    /// The matrix uses the SVG convention `[a, b, c, d, e, f]`, mapping
+    /// `(x, y)` to `(a*x + c*y + e, b*x + d*y + f)`. It is applied in sketch
+    /// space (meters), before scaling and the y-axis flip. Circle radii are
+    /// scaled by `sqrt(|ad - bc|)`, which is exact for similarity transforms.
+    pub fn with_transform(mut self, matrix: [f64; 6]) -> Self {
+        self.transform = Some(matrix);
+        self
+    }
+
+    /// Translate a single line or circle by `(dx, dy)` meters at export time
+    ///
+    /// The offset is applied in sketch space before any affine transform and
+    /// does not affect the solved geometry, so the same sub-sketch can be
+    /// drawn at several locations. Setting an offset for an entity again
+    /// replaces the previous one.
+    pub fn with_instance_offset(mut self, entity: impl Into<EntityRef>, dx: f64, dy: f64) -> Self {
+        self.instance_offsets.insert(entity.into(), (dx, dy));
+        self
+    }
+
+    /// Set the number of decimal places used for coordinates and lengths
+    ///
+    /// Applies to the view box, line endpoints, circle centers and radii, in
+    /// SVG units. The default of 2 gives 0.01mm resolution at the default
+    /// scale. Stroke widths are written as given.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Draw a light reference grid behind the geometry
+    ///
+    /// Grid lines are emitted before any geometry at every multiple of
+    /// `spacing` that falls inside the drawing's bounding box, spanning the
+    /// box from edge to edge. The grid is axis-aligned in the output; its
+    /// spacing is converted to SVG units like any other length. Export fails
+    /// if the spacing is not positive.
+    pub fn with_grid(mut self, spacing: Length) -> Self {
+        self.grid = Some(spacing);
+        self
+    }
+
+    /// Render grid lines covering `[min_x, max_x] × [min_y, max_y]` in SVG units
+    fn grid_lines(&self, spacing: Length, min: (f64, f64), max: (f64, f64)) -> Result<String> {
+        let step = self.to_svg_length(spacing.to_meters());
+        if !(step.is_finite() && step > 0.0) {
+            return Err(TextCadError::InvalidParameter(format!(
+                "Grid spacing must be positive, got {}m",
+                spacing.to_meters()
+            )));
+        }
+
+        // Small tolerance so bounds landing exactly on a grid line keep it
+        let indices = |lo: f64, hi: f64| {
+            let first = (lo / step - 1e-9).ceil() as i64;
+            let last = (hi / step + 1e-9).floor() as i64;
+            first..=last
+        };
+
+        let mut grid = String::new();
+        let stroke_width = self.stroke_width / 4.0;
+        for i in indices(min.0, max.0) {
+            let x = i as f64 * step + 0.0;
+            grid.push_str(&format!(
+                r#"  <line x1="{:.p$}" y1="{:.p$}" x2="{:.p$}" y2="{:.p$}" stroke="lightgray" stroke-width="{}"/>"#,
+                x, min.1, x, max.1, stroke_width,
+                p = self.precision
+            ));
+            grid.push('\n');
+        }
+        for i in indices(min.1, max.1) {
+            let y = i as f64 * step + 0.0;
+            grid.push_str(&format!(
+                r#"  <line x1="{:.p$}" y1="{:.p$}" x2="{:.p$}" y2="{:.p$}" stroke="lightgray" stroke-width="{}"/>"#,
+                min.0, y, max.0, y, stroke_width,
+                p = self.precision
+            ));
+            grid.push('\n');
+        }
+        Ok(grid)
+    }
+
+    /// Render several solved sketches into one SVG document
+    ///
+    /// Each sketch is translated by its `(dx, dy)` offset in meters, applied
+    /// in sketch space on top of any instance offset and before the affine
+    /// transform. The view box covers all sketches, and each sketch's lines
+    /// and circles are emitted in item order. Instance offsets are keyed by
+    /// entity ID, so they apply to the matching entity in every sketch that
+    /// has one.
+    pub fn export_multi(&self, items: &[(&Sketch, &Solution, (f64, f64))]) -> Result<String> {
+        let mut svg = String::new();
+
+        // Calculate bounding box
+        let mut min_x = f64::MAX;
+        let mut min_y = f64::MAX;
+        let mut max_x = f64::MIN;
+        let mut max_y = f64::MIN;
+        let mut include = |(x, y): (f64, f64)| {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        };
+
+        for &(_, solution, (dx, dy)) in items {
+            for &(px, py) in solution.all_point_coordinates().values() {
+                include(self.to_svg_coords(px + dx, py + dy));
+            }
+
+            // Offset entities may be drawn away from their solved points
+            for &entity in self.instance_offsets.keys() {
+                // Skip entities this sketch does not have
+                let anchors = match entity {
+                    EntityRef::Line(line_id) => solution
+                        .get_line_parameters(line_id)
+                        .map(|params| vec![params.start, params.end])
+                        .unwrap_or_default(),
+                    EntityRef::Circle(circle_id) => solution
+                        .get_circle_parameters(circle_id)
+                        .map(|params| vec![params.center])
+                        .unwrap_or_default(),
+                    _ => Vec::new(),
+                };
+                for anchor in anchors {
+                    let (px, py) = self.apply_offsets(entity, anchor, (dx, dy));
+                    include(self.to_svg_coords(px, py));
+                }
+            }
+        }
+
+        // An empty drawing gets a view box around the origin
+        if min_x > max_x {
+            (min_x, min_y, max_x, max_y) = (0.0, 0.0, 0.0, 0.0);
+        }
+
+        let width = max_x - min_x + 2.0 * self.view_box_padding;
+        let height = max_y - min_y + 2.0 * self.view_box_padding;
+
+        svg.push_str(&format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{:.p$} {:.p$} {:.p$} {:.p$}">"#,
+            min_x - self.view_box_padding,
+            min_y - self.view_box_padding,
+            width,
+            height,
+            p = self.precision
+        ));
+        svg.push('\n');
+
+        // Background grid goes first so geometry draws on top
+        if let Some(spacing) = self.grid {
+            svg.push_str(&self.grid_lines(spacing, (min_x, min_y), (max_x, max_y))?);
+        }
+
+        for &(sketch, solution, offset) in items {
+            svg.push_str(&self.geometry(sketch, solution, offset)?);
+        }
+
+        svg.push_str("</svg>\n");
+
+        Ok(svg)
+    }
+
+    /// Render one sketch's lines and circles, translated by `offset` meters
+    fn geometry(&self, sketch: &Sketch, solution: &Solution, offset: (f64, f64)) -> Result<String> {
+        let mut svg = String::new();
+
+        // Export lines in stable index order
+        let mut lines: Vec<_> = sketch.lines().collect();
+        lines.sort_by_key(|(id, _)| *id);
+        for (line_id, line) in lines {
+            let params = solution.get_line_parameters(line_id)?;
+            let entity = EntityRef::Line(line_id);
+            let (x1, y1) = self.apply_offsets(entity, params.start, offset);
+            let (x2, y2) = self.apply_offsets(entity, params.end, offset);
+            let (x1, y1) = self.to_svg_coords(x1, y1);
+            let (x2, y2) = self.to_svg_coords(x2, y2);
+
+            svg.push_str(&format!(
+                r#"  <line x1="{:.p$}" y1="{:.p$}" x2="{:.p$}" y2="{:.p$}" {} stroke-width="{}"/>"#,
+                x1,
+                y1,
+                x2,
+                y2,
+                style_attributes(&line.metadata),
+                self.stroke_width,
+                p = self.precision
+            ));
+            svg.push('\n');
+        }
+
+        // Export circles in stable index order
+        let mut circles: Vec<_> = sketch.circles().collect();
+        circles.sort_by_key(|(id, _)| *id);
+        for (circle_id, circle) in circles {
+            let params = solution.get_circle_parameters(circle_id)?;
+            let (cx, cy) = self.apply_offsets(EntityRef::Circle(circle_id), params.center, offset);
+            let (cx, cy) = self.to_svg_coords(cx, cy);
+            let r = self.to_svg_length(params.radius);
+
+            svg.push_str(&format!(
+                r#"  <circle cx="{:.p$}" cy="{:.p$}" r="{:.p$}" fill="none"{} stroke-width="{}"/>"#,
+                cx,
+                cy,
+                r,
+                style_attributes(&circle.metadata),
+                self.stroke_width,
+                p = self.precision
+            ));
+            svg.push('\n');
+        }
+
+        Ok(svg)
+    }
+
+    /// Translate a sketch-space point by its entity's instance offset and `offset`
+    fn apply_offsets(
+        &self,
+        entity: EntityRef,
+        (x, y): (f64, f64),
+        offset: (f64, f64),
+    ) -> (f64, f64) {
+        let (dx, dy) = self
+            .instance_offsets
+            .get(&entity)
+            .copied()
+            .unwrap_or((0.0, 0.0));
+        (x + dx + offset.0, y + dy + offset.1)
+    }
+
+    fn apply_transform(&self, x: f64, y: f64) -> (f64, f64) {
+        match self.transform {
+            Some([a, b, c, d, e, f]) => (a * x + c * y + e, b * x + d * y + f),
+            None => (x, y),
+        }
+    }
+
+    fn to_svg_coords(&self, x: f64, y: f64) -> (f64, f64) {
+        let (x, y) = self.apply_transform(x, y);
+        // Flip Y for SVG; adding 0.0 turns -0.0 into 0.0 so output never shows "-0.00"
+        (x * self.scale + 0.0, -y * self.scale + 0.0)
+    }
+
+    fn to_svg_length(&self, length: f64) -> f64 {
+        let factor = match self.transform {
+            Some([a, b, c, d, _, _]) => (a * d - b * c).abs().sqrt(),
+            None => 1.0,
+        };
+        length * factor * self.scale
+    }
+}
+
+impl Exporter for SVGExporter {
+    fn export(&self, sketch: &Sketch, solution: &Solution) -> Result<String> {
+        self.export_multi(&[(sketch, solution, (0.0, 0.0))])
+    }
+}
+
+/// Render `class` and `stroke` attributes from entity metadata
+///
+/// The layer becomes the `class`; the color replaces the default black stroke.
+fn style_attributes(metadata: &EntityMetadata) -> String {
+    let mut attributes = String::new();
+    if let Some(layer) = &metadata.layer {
+        attributes.push_str(&format!(r#" class="{}""#, escape_attribute(layer)));
+    }
+    let color = metadata.color.as_deref().unwrap_or("black");
+    attributes.push_str(&format!(r#" stroke="{}""#, escape_attribute(color)));
+    attributes
+}
+
+/// Escape characters that are not allowed inside a double-quoted attribute
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_svg_coords_flips_y() {
+        let exporter = SVGExporter::new();
+        let (x, y) = exporter.to_svg_coords(0.1, 0.2);
+
+        assert!((x - 100.0).abs() < 1e-10);
+        assert!((y + 200.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_translation_transform() {
+        let exporter = SVGExporter::new().with_transform([1.0, 0.0, 0.0, 1.0, 1.0, 0.0]);
+        let (x, y) = exporter.to_svg_coords(0.0, 0.5);
+
+        assert!((x - 1000.0).abs() < 1e-10);
+        assert!((y + 500.0).abs() < 1e-10);
+        // Pure translation leaves lengths unchanged
+        assert!((exporter.to_svg_length(0.5) - 500.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_uniform_scale_transform_scales_lengths() {
+        let exporter = SVGExporter::new().with_transform([2.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+
+        assert!((exporter.to_svg_length(0.5) - 1000.0).abs() < 1e-10);
+    }
+}
@@ -9,20 +9,28 @@ pub mod constraints;
 pub mod entities;
 pub mod entity;
 pub mod error;
+pub mod export;
 pub mod sketch;
 pub mod solution;
 pub mod solver;
 pub mod units;
 
 // Re-export commonly used types
-pub use constraint::{Constraint, ConstraintFactory, SketchQuery};
+pub use constraint::{
+    Constraint, ConstraintFactory, ConstraintGraph, ConstraintId, ConstraintVisitor, EntityRef,
+    SketchQuery,
+};
 pub use constraints::{
     CoincidentPointsConstraint, FixedPositionConstraint, LineLengthConstraint,
     ParallelLinesConstraint, PerpendicularLinesConstraint, PointOnLineConstraint,
 };
-pub use entities::{Circle, Line, Point2D, PointId};
-pub use entity::{CircleId, LineId};
+pub use entities::{Arc, Circle, Ellipse, EntityMetadata, Line, Point2D, PointId};
+pub use entity::{ArcId, CircleId, EllipseId, LineId};
 pub use error::{Result, SolverResult, TextCadError};
-pub use sketch::Sketch;
-pub use solution::{CircleParameters, LineParameters, Solution};
-pub use units::{Angle, Area, Length};
+pub use export::{CSVExporter, Exporter, SVGExporter};
+pub use sketch::{
+    CheckpointId, ComplexityEstimate, Coord, ParamDescriptor, Sketch, SolverParamKind,
+    SolverParamValue,
+};
+pub use solution::{CircleParameters, GeometrySnapshot, LineParameters, Solution};
+pub use units::{Angle, Area, Length, Scalar};
@@ -4,13 +4,109 @@
 //! geometric entities and constraints using Z3 as the underlying solver.
 
 use generational_arena::Arena;
-use z3::{Context, SatResult, Solver};
-
-use crate::constraint::{Constraint, SketchQuery};
-use crate::entities::{Circle, Line, Point2D, PointId};
-use crate::entity::{CircleId, LineId};
+use std::ops::Sub;
+use std::sync;
+use std::time::{Duration, Instant};
+use z3::ast::{Ast, Bool, Real};
+use z3::{Context, DeclKind, Model, Optimize, Params, SatResult, Solver};
+
+use crate::constraint::{
+    Constraint, ConstraintGraph, ConstraintId, ConstraintVisitor, EntityRef, SketchQuery,
+};
+use crate::constraints::{
+    CircleRadiusConstraint, FixedPositionConstraint, LineLengthConstraint, MidpointConstraint,
+    ParallelLinesConstraint, PerpendicularLinesConstraint, real_from_f64,
+};
+use crate::entities::{Arc, Circle, Ellipse, EntityMetadata, Line, Point2D, PointId};
+use crate::entity::{ArcId, CircleId, EllipseId, LineId};
 use crate::error::{Result, TextCadError};
-use crate::solution::Solution;
+use crate::solution::{Solution, large_rational_to_f64};
+use crate::units::Length;
+
+/// Value for a Z3 solver parameter, see `Sketch::set_solver_param`
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolverParamValue {
+    /// Boolean parameter such as `auto_config`
+    Bool(bool),
+    /// Unsigned integer parameter such as `timeout` (milliseconds)
+    U32(u32),
+    /// Symbol-valued parameter
+    Str(String),
+}
+
+/// Kind of value a solver parameter takes, see `ParamDescriptor`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverParamKind {
+    /// Takes `SolverParamValue::Bool`
+    Bool,
+    /// Takes `SolverParamValue::U32`
+    U32,
+    /// Takes `SolverParamValue::Str`
+    Str,
+}
+
+/// Description of a tunable Z3 solver parameter, see `Sketch::available_params`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamDescriptor {
+    /// Key to pass to `Sketch::set_solver_param`
+    pub name: &'static str,
+    /// Kind of value the parameter takes
+    pub kind: SolverParamKind,
+    /// Z3's value when the parameter is not set
+    pub default: SolverParamValue,
+    /// One-line explanation for display
+    pub description: &'static str,
+}
+
+/// Coordinate axis of a point, see `Sketch::free_coordinates`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coord {
+    /// The x coordinate
+    X,
+    /// The y coordinate
+    Y,
+}
+
+/// Identifier for a saved sketch state, see `Sketch::checkpoint`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CheckpointId(pub usize);
+
+/// Entities and constraints captured by `Sketch::checkpoint`
+///
+/// Constraints are shared with the live sketch, so a checkpoint costs one
+/// clone of each entity arena plus a reference count per constraint.
+struct Checkpoint<'ctx> {
+    points: Arena<Point2D<'ctx>>,
+    lines: Arena<Line>,
+    circles: Arena<Circle<'ctx>>,
+    arcs: Arena<Arc<'ctx>>,
+    ellipses: Arena<Ellipse<'ctx>>,
+    constraints: Vec<sync::Arc<dyn Constraint>>,
+    origin: Option<PointId>,
+}
+
+/// Heuristic difficulty of a sketch, see `Sketch::complexity_estimate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComplexityEstimate {
+    /// Constraints whose assertions are all linear
+    pub linear_constraints: usize,
+    /// Constraints with at least one nonlinear assertion
+    pub nonlinear_constraints: usize,
+    /// Z3 variables owned by entities (auxiliary constraint variables excluded)
+    pub variables: usize,
+    /// Whether the nonlinear constraint count exceeds `HARD_NONLINEAR_THRESHOLD`
+    pub likely_hard: bool,
+}
+
+impl ComplexityEstimate {
+    /// Nonlinear constraint count above which a sketch is flagged as likely hard
+    pub const HARD_NONLINEAR_THRESHOLD: usize = 10;
+
+    /// Whether nonlinear constraints outnumber linear ones
+    pub fn nonlinear_dominant(&self) -> bool {
+        self.nonlinear_constraints > self.linear_constraints
+    }
+}
 
 /// Main sketch structure that manages geometric entities and constraints
 ///
@@ -25,8 +121,27 @@ pub struct Sketch<'ctx> {
     lines: Arena<Line>,
     /// Arena for managing Circle entities
     circles: Arena<Circle<'ctx>>,
+    /// Arena for managing Arc entities
+    arcs: Arena<Arc<'ctx>>,
+    /// Arena for managing Ellipse entities
+    ellipses: Arena<Ellipse<'ctx>>,
     /// Vector of constraints that have been added to the sketch
-    constraints: Vec<Box<dyn Constraint>>,
+    constraints: Vec<sync::Arc<dyn Constraint>>,
+    /// Datum point fixed at (0, 0), created on first use
+    origin: Option<PointId>,
+    /// Whether entities, constraints, or solver state changed since the last solve
+    dirty: bool,
+    /// Number of times `solve_and_extract` actually ran the solver
+    solve_count: usize,
+    /// Wall-clock time of the last solve run by `solve_and_extract`
+    last_solve_time: Option<Duration>,
+    /// Parameters set through `set_solver_param`, replayed when the solver is rebuilt
+    solver_params: Vec<(String, SolverParamValue)>,
+    /// Whether the last solve's constraint assertions are still on the solver,
+    /// in their own push scope
+    constraints_applied: bool,
+    /// Saved states, indexed by `CheckpointId`
+    checkpoints: Vec<Checkpoint<'ctx>>,
 }
 
 impl<'ctx> Sketch<'ctx> {
@@ -49,6 +164,8 @@ impl<'ctx> Sketch<'ctx> {
         let points = Arena::new();
         let lines = Arena::new();
         let circles = Arena::new();
+        let arcs = Arena::new();
+        let ellipses = Arena::new();
         let constraints = Vec::new();
         Self {
             ctx,
@@ -56,7 +173,16 @@ impl<'ctx> Sketch<'ctx> {
             points,
             lines,
             circles,
+            arcs,
+            ellipses,
             constraints,
+            origin: None,
+            dirty: true,
+            solve_count: 0,
+            last_solve_time: None,
+            solver_params: Vec::new(),
+            constraints_applied: false,
+            checkpoints: Vec::new(),
         }
     }
 
@@ -72,11 +198,38 @@ impl<'ctx> Sketch<'ctx> {
 
     /// Get a mutable reference to the underlying Z3 solver
     ///
-    /// This allows adding assertions directly to the solver
+    /// This allows adding assertions directly to the solver. Constraint
+    /// assertions left over from the last solve are dropped first, so direct
+    /// assertions persist across solves.
     pub fn solver_mut(&mut self) -> &mut Solver<'ctx> {
+        self.dirty = true;
+        self.clear_applied_constraints();
         &mut self.solver
     }
 
+    /// Pop the scope holding the last solve's constraint assertions, if any
+    ///
+    /// Every method that applies constraints calls this first, so a failed
+    /// solve cannot leave conflicting assertions behind for later checks.
+    fn clear_applied_constraints(&mut self) {
+        if self.constraints_applied {
+            self.solver.pop(1);
+            self.constraints_applied = false;
+        }
+    }
+
+    /// Get the solver's current assertions as readable strings
+    ///
+    /// Returns one entry per assertion, which is easier to scan than a full
+    /// SMT-LIB dump when debugging constraint encodings.
+    pub fn assertions_as_strings(&self) -> Vec<String> {
+        self.solver
+            .get_assertions()
+            .iter()
+            .map(|assertion| assertion.to_string())
+            .collect()
+    }
+
     /// Check if the current constraint system is satisfiable
     ///
     /// Returns the satisfiability result from Z3
@@ -89,7 +242,21 @@ impl<'ctx> Sketch<'ctx> {
     /// This is a convenience method that wraps `check()` and provides
     /// better error reporting for common failure cases.
     pub fn solve(&mut self) -> Result<SatResult> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::span!(tracing::Level::INFO, "solve").entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
         let result = self.solver.check();
+
+        #[cfg(feature = "tracing")]
+        tracing::event!(
+            tracing::Level::INFO,
+            result = ?result,
+            elapsed_us = start.elapsed().as_micros() as u64,
+            "solver check finished"
+        );
+
         match result {
             SatResult::Sat => Ok(result),
             SatResult::Unsat => Err(TextCadError::OverConstrained),
@@ -99,6 +266,152 @@ impl<'ctx> Sketch<'ctx> {
         }
     }
 
+    /// Set a parameter on the sketch's Z3 solver
+    ///
+    /// Parameters persist across solves. Z3 rejects unknown keys or values of
+    /// the wrong kind, so only use documented solver parameters.
+    ///
+    /// # Arguments
+    /// * `key` - Z3 parameter name, e.g. `"auto_config"` or `"timeout"`
+    /// * `value` - Value to assign
+    pub fn set_solver_param(&mut self, key: &str, value: SolverParamValue) {
+        self.dirty = true;
+        self.solver_params.push((key.to_string(), value.clone()));
+        self.apply_solver_param(key, value);
+    }
+
+    /// List the solver parameters a front-end can offer for `set_solver_param`
+    ///
+    /// A curated subset of Z3's solver parameters that are useful for sketch
+    /// solving, with Z3's defaults. Any other documented Z3 parameter can
+    /// still be set by name.
+    pub fn available_params(&self) -> Vec<ParamDescriptor> {
+        vec![
+            ParamDescriptor {
+                name: "timeout",
+                kind: SolverParamKind::U32,
+                default: SolverParamValue::U32(u32::MAX),
+                description: "Time limit per solve in milliseconds",
+            },
+            ParamDescriptor {
+                name: "rlimit",
+                kind: SolverParamKind::U32,
+                default: SolverParamValue::U32(0),
+                description: "Deterministic resource limit per solve (0 for none)",
+            },
+            ParamDescriptor {
+                name: "random_seed",
+                kind: SolverParamKind::U32,
+                default: SolverParamValue::U32(0),
+                description: "Seed for the solver's randomized heuristics",
+            },
+            ParamDescriptor {
+                name: "auto_config",
+                kind: SolverParamKind::Bool,
+                default: SolverParamValue::Bool(true),
+                description: "Let Z3 pick a tactic configuration for the problem",
+            },
+            ParamDescriptor {
+                name: "unsat_core",
+                kind: SolverParamKind::Bool,
+                default: SolverParamValue::Bool(false),
+                description: "Track unsat cores when a solve fails",
+            },
+        ]
+    }
+
+    fn apply_solver_param(&self, key: &str, value: SolverParamValue) {
+        let mut params = Params::new(self.ctx);
+        match value {
+            SolverParamValue::Bool(v) => params.set_bool(key, v),
+            SolverParamValue::U32(v) => params.set_u32(key, v),
+            SolverParamValue::Str(v) => params.set_symbol(key, v),
+        }
+        self.solver.set_params(&params);
+    }
+
+    /// Timeout in milliseconds most recently set through `set_solver_param`
+    fn solver_timeout_ms(&self) -> Option<u32> {
+        self.solver_params
+            .iter()
+            .rev()
+            .find_map(|(key, value)| match (key.as_str(), value) {
+                ("timeout", SolverParamValue::U32(ms)) => Some(*ms),
+                _ => None,
+            })
+    }
+
+    /// Check a Z3 optimizer, honoring a `timeout` set through `set_solver_param`
+    ///
+    /// The z3 bindings cannot pass parameters to an `Optimize`, so the timeout
+    /// is enforced by a watchdog thread that interrupts the context once it
+    /// elapses, making the check return `SatResult::Unknown`.
+    fn check_optimizer(&self, optimize: &Optimize<'ctx>) -> SatResult {
+        let Some(ms) = self.solver_timeout_ms() else {
+            return optimize.check(&[]);
+        };
+
+        let handle = self.ctx.handle();
+        let (done, finished) = sync::mpsc::channel::<()>();
+        std::thread::scope(|scope| {
+            scope.spawn(move || {
+                let waited = finished.recv_timeout(Duration::from_millis(ms.into()));
+                if let Err(sync::mpsc::RecvTimeoutError::Timeout) = waited {
+                    handle.interrupt();
+                }
+            });
+            let result = optimize.check(&[]);
+            drop(done);
+            result
+        })
+    }
+
+    /// Save the current entities and constraints for a later `restore`
+    ///
+    /// Only sketch metadata is captured, not Z3 solver state. Checkpoints
+    /// are kept after being restored, so the same ID can be restored again,
+    /// including to move forward after restoring an earlier one.
+    pub fn checkpoint(&mut self) -> CheckpointId {
+        self.checkpoints.push(Checkpoint {
+            points: self.points.clone(),
+            lines: self.lines.clone(),
+            circles: self.circles.clone(),
+            arcs: self.arcs.clone(),
+            ellipses: self.ellipses.clone(),
+            constraints: self.constraints.clone(),
+            origin: self.origin,
+        });
+        CheckpointId(self.checkpoints.len() - 1)
+    }
+
+    /// Return the sketch to a state saved by `checkpoint`
+    ///
+    /// Entities and constraints are replaced by the saved ones and the
+    /// solver is rebuilt from scratch with the parameters set through
+    /// `set_solver_param`, so the next solve starts clean. Assertions added
+    /// directly through `solver_mut` are discarded.
+    pub fn restore(&mut self, id: CheckpointId) -> Result<()> {
+        let checkpoint = self.checkpoints.get(id.0).ok_or_else(|| {
+            TextCadError::InvalidParameter(format!("Checkpoint {:?} not found", id))
+        })?;
+
+        self.points = checkpoint.points.clone();
+        self.lines = checkpoint.lines.clone();
+        self.circles = checkpoint.circles.clone();
+        self.arcs = checkpoint.arcs.clone();
+        self.ellipses = checkpoint.ellipses.clone();
+        self.constraints = checkpoint.constraints.clone();
+        self.origin = checkpoint.origin;
+
+        self.solver = Solver::new(self.ctx);
+        self.constraints_applied = false;
+        for (key, value) in &self.solver_params {
+            self.apply_solver_param(key, value.clone());
+        }
+        self.dirty = true;
+        Ok(())
+    }
+
     /// Add a new point to the sketch
     ///
     /// Creates a new Point2D with Z3 symbolic variables for its coordinates
@@ -122,6 +435,7 @@ impl<'ctx> Sketch<'ctx> {
     /// let p2 = sketch.add_point(None);
     /// ```
     pub fn add_point(&mut self, name: Option<String>) -> PointId {
+        self.dirty = true;
         let idx = self.points.insert_with(|idx| {
             let id = PointId::from(idx);
             Point2D::new(id, self.ctx, name)
@@ -152,6 +466,103 @@ impl<'ctx> Sketch<'ctx> {
         self.points.get(id.into())
     }
 
+    /// Iterate over all points in the sketch with their IDs
+    pub fn points(&self) -> impl Iterator<Item = (PointId, &Point2D<'ctx>)> {
+        self.points
+            .iter()
+            .map(|(idx, point)| (PointId::from(idx), point))
+    }
+
+    /// Remove a point from the sketch
+    ///
+    /// Fails with `TextCadError::EntityError` if the point does not exist, is
+    /// the sketch origin, or a line, circle, arc or ellipse still references
+    /// it. The origin is kept because its internal `FixedPositionConstraint`
+    /// cannot be dropped without renumbering constraints. Constraints are not
+    /// inspected otherwise; any that reference the removed point will fail
+    /// when the sketch is next solved.
+    /// The solver is reset so stale assertions on the point's variables are
+    /// dropped; constraints are re-applied on the next solve.
+    pub fn remove_point(&mut self, p: PointId) -> Result<()> {
+        if self.get_point(p).is_none() {
+            return Err(TextCadError::EntityError(format!(
+                "Point {:?} not found",
+                p
+            )));
+        }
+        if let Some((line_id, _)) = self
+            .lines()
+            .find(|(_, line)| line.start == p || line.end == p)
+        {
+            return Err(TextCadError::EntityError(format!(
+                "Point {:?} is referenced by line {:?}",
+                p, line_id
+            )));
+        }
+        if let Some((circle_id, _)) = self.circles().find(|(_, circle)| circle.center == p) {
+            return Err(TextCadError::EntityError(format!(
+                "Point {:?} is the center of circle {:?}",
+                p, circle_id
+            )));
+        }
+        if let Some((arc_id, _)) = self.arcs().find(|(_, arc)| arc.start == p || arc.end == p) {
+            return Err(TextCadError::EntityError(format!(
+                "Point {:?} is an endpoint of arc {:?}",
+                p, arc_id
+            )));
+        }
+        if let Some((ellipse_id, _)) = self.ellipses().find(|(_, ellipse)| ellipse.center == p) {
+            return Err(TextCadError::EntityError(format!(
+                "Point {:?} is the center of ellipse {:?}",
+                p, ellipse_id
+            )));
+        }
+        if self.origin == Some(p) {
+            return Err(TextCadError::EntityError(format!(
+                "Point {:?} is the sketch origin",
+                p
+            )));
+        }
+
+        self.points.remove(p.into());
+        self.solver.reset();
+        self.constraints_applied = false;
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Get the sketch's datum point at the origin
+    ///
+    /// On first call this creates a point named "origin" and fixes it at (0, 0)
+    /// with a FixedPositionConstraint; later calls return the same PointId.
+    /// Requires `&mut self` because the point is created lazily.
+    ///
+    /// # Example
+    /// ```
+    /// use z3::{Config, Context};
+    /// use textcad::sketch::Sketch;
+    ///
+    /// let cfg = Config::new();
+    /// let ctx = Context::new(&cfg);
+    /// let mut sketch = Sketch::new(&ctx);
+    /// let origin = sketch.origin();
+    /// assert_eq!(origin, sketch.origin());
+    /// ```
+    pub fn origin(&mut self) -> PointId {
+        if let Some(origin) = self.origin {
+            return origin;
+        }
+
+        let origin = self.add_point(Some("origin".to_string()));
+        self.add_constraint(FixedPositionConstraint::new(
+            origin,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        self.origin = Some(origin);
+        origin
+    }
+
     /// Add a new line to the sketch
     ///
     /// Creates a new Line that connects two existing points and adds it to the lines arena.
@@ -177,6 +588,7 @@ impl<'ctx> Sketch<'ctx> {
     /// let line = sketch.add_line(p1, p2, Some("line1".to_string()));
     /// ```
     pub fn add_line(&mut self, start: PointId, end: PointId, name: Option<String>) -> LineId {
+        self.dirty = true;
         let idx = self.lines.insert_with(|idx| {
             let id = LineId::from(idx);
             Line::new(id, start, end, name)
@@ -184,6 +596,33 @@ impl<'ctx> Sketch<'ctx> {
         LineId::from(idx)
     }
 
+    /// Add a new line after checking that both endpoints exist
+    ///
+    /// Unlike `add_line`, which defers invalid endpoints to solve time, this
+    /// returns `TextCadError::EntityError` immediately if either PointId is
+    /// not in the sketch.
+    ///
+    /// # Arguments
+    /// * `start` - PointId of the line's start point
+    /// * `end` - PointId of the line's end point
+    /// * `name` - Optional name for the line
+    pub fn try_add_line(
+        &mut self,
+        start: PointId,
+        end: PointId,
+        name: Option<String>,
+    ) -> Result<LineId> {
+        for point in [start, end] {
+            if self.get_point(point).is_none() {
+                return Err(TextCadError::EntityError(format!(
+                    "Point {:?} not found",
+                    point
+                )));
+            }
+        }
+        Ok(self.add_line(start, end, name))
+    }
+
     /// Get a reference to a line by its ID
     ///
     /// # Arguments  
@@ -209,6 +648,87 @@ impl<'ctx> Sketch<'ctx> {
         self.lines.get(id.into())
     }
 
+    /// Tag a line with layer and color metadata for export
+    pub fn set_line_metadata(&mut self, id: LineId, metadata: EntityMetadata) -> Result<()> {
+        let line = self
+            .lines
+            .get_mut(id.into())
+            .ok_or_else(|| TextCadError::EntityError(format!("Line {:?} not found", id)))?;
+        line.metadata = metadata;
+        Ok(())
+    }
+
+    /// Add a new point constrained to the midpoint of a line
+    ///
+    /// Creates the point and adds a MidpointConstraint in one call.
+    ///
+    /// # Arguments
+    /// * `line` - LineId of the line whose midpoint to create
+    /// * `name` - Optional name for the new point
+    ///
+    /// # Returns
+    /// PointId of the new midpoint
+    pub fn add_midpoint(&mut self, line: LineId, name: Option<String>) -> PointId {
+        let point = self.add_point(name);
+        self.add_constraint(MidpointConstraint::new(line, point));
+        point
+    }
+
+    /// Add a rectangle anchored at an existing corner point
+    ///
+    /// Creates three new corners and four lines running `origin -> b -> c -> d
+    /// -> origin`, plus constraints fixing the first side to `width`, the
+    /// second to `height`, opposite sides parallel and the first corner square.
+    /// The rectangle's orientation about `origin` is left free.
+    ///
+    /// # Returns
+    /// The corners `[origin, b, c, d]` and the lines `[ab, bc, cd, da]`
+    pub fn add_rectangle(
+        &mut self,
+        origin: PointId,
+        width: Length,
+        height: Length,
+    ) -> ([PointId; 4], [LineId; 4]) {
+        let b = self.add_point(None);
+        let c = self.add_point(None);
+        let d = self.add_point(None);
+
+        let ab = self.add_line(origin, b, None);
+        let bc = self.add_line(b, c, None);
+        let cd = self.add_line(c, d, None);
+        let da = self.add_line(d, origin, None);
+
+        self.add_constraint(LineLengthConstraint::new(ab, width));
+        self.add_constraint(LineLengthConstraint::new(bc, height));
+        self.add_constraint(ParallelLinesConstraint::new(ab, cd));
+        self.add_constraint(ParallelLinesConstraint::new(bc, da));
+        self.add_constraint(PerpendicularLinesConstraint::new(ab, bc));
+
+        ([origin, b, c, d], [ab, bc, cd, da])
+    }
+
+    /// Remove a line from the sketch
+    ///
+    /// The line's endpoints are kept. Fails with `TextCadError::EntityError` if
+    /// the line does not exist. As with `remove_point`, constraints referencing
+    /// the line fail on the next solve, and the solver is reset.
+    pub fn remove_line(&mut self, l: LineId) -> Result<()> {
+        self.lines
+            .remove(l.into())
+            .ok_or_else(|| TextCadError::EntityError(format!("Line {:?} not found", l)))?;
+        self.solver.reset();
+        self.constraints_applied = false;
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Iterate over all lines in the sketch with their IDs
+    pub fn lines(&self) -> impl Iterator<Item = (LineId, &Line)> {
+        self.lines
+            .iter()
+            .map(|(idx, line)| (LineId::from(idx), line))
+    }
+
     /// Add a new circle to the sketch
     ///
     /// Creates a new Circle with a center point and radius and adds it to the circles arena.
@@ -233,6 +753,7 @@ impl<'ctx> Sketch<'ctx> {
     /// let circle = sketch.add_circle(center, Some("circle1".to_string()));
     /// ```
     pub fn add_circle(&mut self, center: PointId, name: Option<String>) -> CircleId {
+        self.dirty = true;
         let idx = self.circles.insert_with(|idx| {
             let id = CircleId::from(idx);
             Circle::new(id, center, self.ctx, name)
@@ -240,6 +761,28 @@ impl<'ctx> Sketch<'ctx> {
         CircleId::from(idx)
     }
 
+    /// Add a new circle with a fixed radius
+    ///
+    /// Creates the circle and adds a `CircleRadiusConstraint` in one call.
+    ///
+    /// # Arguments
+    /// * `center` - PointId of the center point
+    /// * `radius` - Radius of the circle
+    /// * `name` - Optional name for debugging and display
+    ///
+    /// # Returns
+    /// CircleId of the new circle
+    pub fn add_circle_with_radius(
+        &mut self,
+        center: PointId,
+        radius: Length,
+        name: Option<String>,
+    ) -> CircleId {
+        let circle = self.add_circle(center, name);
+        self.add_constraint(CircleRadiusConstraint::new(circle, radius));
+        circle
+    }
+
     /// Get a reference to a circle by its ID
     ///
     /// # Arguments  
@@ -264,92 +807,811 @@ impl<'ctx> Sketch<'ctx> {
         self.circles.get(id.into())
     }
 
+    /// Tag a circle with layer and color metadata for export
+    pub fn set_circle_metadata(&mut self, id: CircleId, metadata: EntityMetadata) -> Result<()> {
+        let circle = self
+            .circles
+            .get_mut(id.into())
+            .ok_or_else(|| TextCadError::EntityError(format!("Circle {:?} not found", id)))?;
+        circle.metadata = metadata;
+        Ok(())
+    }
+
+    /// Iterate over all circles in the sketch with their IDs
+    pub fn circles(&self) -> impl Iterator<Item = (CircleId, &Circle<'ctx>)> {
+        self.circles
+            .iter()
+            .map(|(idx, circle)| (CircleId::from(idx), circle))
+    }
+
+    /// Add a new arc to the sketch
+    ///
+    /// The arc runs on `circle` from `start` to `end`. The endpoints are not
+    /// constrained onto the circle automatically.
+    ///
+    /// # Arguments
+    /// * `circle` - CircleId of the supporting circle
+    /// * `start` - PointId of the start point
+    /// * `end` - PointId of the end point
+    /// * `name` - Optional name for debugging and display
+    ///
+    /// # Returns
+    /// ArcId that can be used to reference this arc
+    pub fn add_arc(
+        &mut self,
+        circle: CircleId,
+        start: PointId,
+        end: PointId,
+        name: Option<String>,
+    ) -> ArcId {
+        self.dirty = true;
+        let idx = self.arcs.insert_with(|idx| {
+            let id = ArcId::from(idx);
+            Arc::new(id, circle, start, end, self.ctx, name)
+        });
+        ArcId::from(idx)
+    }
+
+    /// Get a reference to an arc by its ID
+    pub fn get_arc(&self, id: ArcId) -> Option<&Arc<'ctx>> {
+        self.arcs.get(id.into())
+    }
+
+    /// Iterate over all arcs in the sketch with their IDs
+    pub fn arcs(&self) -> impl Iterator<Item = (ArcId, &Arc<'ctx>)> {
+        self.arcs.iter().map(|(idx, arc)| (ArcId::from(idx), arc))
+    }
+
+    /// Add a new axis-aligned ellipse to the sketch
+    ///
+    /// Creates an Ellipse with the given center and free semi-axis variables.
+    ///
+    /// # Arguments
+    /// * `center` - PointId of the center point
+    /// * `name` - Optional name for debugging and display
+    ///
+    /// # Returns
+    /// EllipseId that can be used to reference this ellipse
+    pub fn add_ellipse(&mut self, center: PointId, name: Option<String>) -> EllipseId {
+        self.dirty = true;
+        let idx = self.ellipses.insert_with(|idx| {
+            let id = EllipseId::from(idx);
+            Ellipse::new(id, center, self.ctx, name)
+        });
+        EllipseId::from(idx)
+    }
+
+    /// Get a reference to an ellipse by its ID
+    pub fn get_ellipse(&self, id: EllipseId) -> Option<&Ellipse<'ctx>> {
+        self.ellipses.get(id.into())
+    }
+
+    /// Iterate over all ellipses in the sketch with their IDs
+    pub fn ellipses(&self) -> impl Iterator<Item = (EllipseId, &Ellipse<'ctx>)> {
+        self.ellipses
+            .iter()
+            .map(|(idx, ellipse)| (EllipseId::from(idx), ellipse))
+    }
+
     /// Add a constraint to the sketch
-    pub fn add_constraint(&mut self, constraint: impl Constraint + 'static) {
-        self.constraints.push(Box::new(constraint));
+    ///
+    /// Returns a ConstraintId identifying the constraint in diagnostics
+    pub fn add_constraint(&mut self, constraint: impl Constraint + 'static) -> ConstraintId {
+        self.add_boxed_constraint(Box::new(constraint))
+    }
+
+    /// Add an already boxed constraint to the sketch
+    pub fn add_boxed_constraint(&mut self, constraint: Box<dyn Constraint>) -> ConstraintId {
+        self.dirty = true;
+        self.constraints.push(sync::Arc::from(constraint));
+        ConstraintId(self.constraints.len() - 1)
+    }
+
+    /// Pin several points at once
+    ///
+    /// Adds a `FixedPositionConstraint` for each `(point, x, y)` entry, in order.
+    pub fn fix_points(&mut self, fixes: &[(PointId, Length, Length)]) {
+        for &(point, x, y) in fixes {
+            self.add_constraint(FixedPositionConstraint::new(point, x, y));
+        }
+    }
+
+    /// Walk all constraints in insertion order with the given visitor
+    pub fn visit_constraints(&self, visitor: &mut dyn ConstraintVisitor) {
+        for constraint in &self.constraints {
+            constraint.accept(visitor);
+        }
+    }
+
+    /// Build the constraint-to-entity adjacency graph
+    ///
+    /// Each constraint maps to the entities it references. Lines, arcs,
+    /// circles and ellipses also pull in their endpoints or center, so
+    /// point-level dependencies are visible without walking the entities again.
+    pub fn constraint_graph(&self) -> ConstraintGraph {
+        let mut graph = ConstraintGraph::default();
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            let entities = graph.edges.entry(ConstraintId(i)).or_default();
+            for entity in constraint.referenced_entities() {
+                entities.insert(entity);
+                match entity {
+                    EntityRef::Line(id) => {
+                        if let Some(line) = self.get_line(id) {
+                            entities.insert(EntityRef::Point(line.start));
+                            entities.insert(EntityRef::Point(line.end));
+                        }
+                    }
+                    EntityRef::Circle(id) => {
+                        if let Some(circle) = self.get_circle(id) {
+                            entities.insert(EntityRef::Point(circle.center));
+                        }
+                    }
+                    EntityRef::Arc(id) => {
+                        if let Some(arc) = self.get_arc(id) {
+                            entities.insert(EntityRef::Circle(arc.circle));
+                            entities.insert(EntityRef::Point(arc.start));
+                            entities.insert(EntityRef::Point(arc.end));
+                        }
+                    }
+                    EntityRef::Ellipse(id) => {
+                        if let Some(ellipse) = self.get_ellipse(id) {
+                            entities.insert(EntityRef::Point(ellipse.center));
+                        }
+                    }
+                    EntityRef::Point(_) => {}
+                }
+            }
+        }
+        graph
+    }
+
+    /// Estimate how expensive the sketch will be to solve
+    ///
+    /// Counts linear versus nonlinear constraints (via
+    /// `Constraint::is_linear`) and the variables owned by entities: two per
+    /// point, one per circle, two per arc and two per ellipse. Linear real
+    /// arithmetic is cheap for Z3, while nonlinear constraints drive solve
+    /// time, so only they contribute to `likely_hard`.
+    pub fn complexity_estimate(&self) -> ComplexityEstimate {
+        let linear_constraints = self
+            .constraints
+            .iter()
+            .filter(|constraint| constraint.is_linear())
+            .count();
+        let nonlinear_constraints = self.constraints.len() - linear_constraints;
+        let variables = 2 * self.points.len()
+            + self.circles.len()
+            + 2 * self.arcs.len()
+            + 2 * self.ellipses.len();
+
+        ComplexityEstimate {
+            linear_constraints,
+            nonlinear_constraints,
+            variables,
+            likely_hard: nonlinear_constraints > ComplexityEstimate::HARD_NONLINEAR_THRESHOLD,
+        }
     }
 
     /// Apply all constraints and solve the system
+    ///
+    /// The constraint assertions go in their own solver scope, which stays in
+    /// place so the model can be read afterwards and is popped before
+    /// constraints are next applied.
     pub fn solve_constraints(&mut self) -> Result<SatResult> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::span!(
+            tracing::Level::INFO,
+            "solve_constraints",
+            constraints = self.constraints.len()
+        )
+        .entered();
+
+        self.clear_applied_constraints();
+        self.solver.push();
+        self.constraints_applied = true;
+
         // Apply all constraints
         for constraint in &self.constraints {
+            #[cfg(feature = "tracing")]
+            let start = std::time::Instant::now();
+
             constraint.apply(self.ctx, &self.solver, self)?;
+
+            #[cfg(feature = "tracing")]
+            tracing::event!(
+                tracing::Level::DEBUG,
+                constraint = %constraint.description(),
+                elapsed_us = start.elapsed().as_micros() as u64,
+                "applied constraint"
+            );
         }
 
         // Solve the constraint system
         self.solve()
     }
 
-    /// Apply all constraints, solve, and return a Solution with extracted coordinates
-    pub fn solve_and_extract(&mut self) -> Result<Solution<'ctx>> {
-        // Apply all constraints and solve
-        self.solve_constraints()?;
+    /// Apply all constraints and report whether the system is satisfiable
+    ///
+    /// Skips model and coordinate extraction. Assertions left on the solver
+    /// by an earlier solve are dropped first, and constraints are applied in
+    /// a temporary solver scope. Unsat maps to `Ok(false)`; an unknown result
+    /// is an error.
+    pub fn is_satisfiable(&mut self) -> Result<bool> {
+        self.dirty = true;
+        self.clear_applied_constraints();
+        let all: Vec<usize> = (0..self.constraints.len()).collect();
+        self.is_subset_satisfiable(&all)
+    }
 
-        // Extract the model
-        let model = self.solver.get_model().ok_or_else(|| {
-            TextCadError::SolverError("No model available after solving".to_string())
-        })?;
+    /// Check whether adding `constraint` would make the sketch unsatisfiable
+    ///
+    /// Drops assertions left on the solver by an earlier solve, then applies
+    /// all constraints plus `constraint` in a temporary solver scope, so the
+    /// constraint list is not changed. Returns `Ok(true)` on unsat; an
+    /// unknown result is an error.
+    pub fn would_overconstrain(&mut self, constraint: &dyn Constraint) -> Result<bool> {
+        self.dirty = true;
+        self.clear_applied_constraints();
+        self.solver.push();
+        let applied = self
+            .constraints
+            .iter()
+            .try_for_each(|existing| existing.apply(self.ctx, &self.solver, self))
+            .and_then(|_| constraint.apply(self.ctx, &self.solver, self));
+        let result = applied.map(|_| self.solver.check());
+        self.solver.pop(1);
+
+        match result? {
+            SatResult::Sat => Ok(false),
+            SatResult::Unsat => Ok(true),
+            SatResult::Unknown => Err(TextCadError::SolverError(
+                "Z3 solver returned unknown result".to_string(),
+            )),
+        }
+    }
 
-        // Create solution and extract all point coordinates
-        let mut solution = Solution::new(model);
+    /// List point coordinates that the constraints leave free
+    ///
+    /// A coordinate counts as free if pinning it to two different values
+    /// (its value in a satisfying model, and that value plus one) is
+    /// satisfiable both times. Assertions left on the solver by an earlier
+    /// solve are dropped first, and all probing happens in temporary solver
+    /// scopes.
+    pub fn free_coordinates(&mut self) -> Result<Vec<(PointId, Coord)>> {
+        self.dirty = true;
+        self.clear_applied_constraints();
+        self.solver.push();
+        let result = self.probe_free_coordinates();
+        self.solver.pop(1);
+        result
+    }
 
-        // Extract coordinates for all points
-        for (idx, point) in self.points.iter() {
-            let point_id = PointId::from(idx);
-            solution.extract_point_coordinates(point_id, &point.x, &point.y)?;
+    /// Apply all constraints and probe each coordinate, see `free_coordinates`
+    fn probe_free_coordinates(&self) -> Result<Vec<(PointId, Coord)>> {
+        for constraint in &self.constraints {
+            constraint.apply(self.ctx, &self.solver, self)?;
         }
 
-        // Extract parameters for all lines
-        for (idx, line) in self.lines.iter() {
-            let line_id = LineId::from(idx);
+        let model = match self.solver.check() {
+            SatResult::Sat => self.solver.get_model().ok_or_else(|| {
+                TextCadError::SolverError("No model available after solving".to_string())
+            })?,
+            SatResult::Unsat => return Err(TextCadError::OverConstrained),
+            SatResult::Unknown => {
+                return Err(TextCadError::SolverError(
+                    "Z3 solver returned unknown result".to_string(),
+                ));
+            }
+        };
 
-            // Get start and end point coordinates
-            let start_coords = solution.get_point_coordinates(line.start)?;
-            let end_coords = solution.get_point_coordinates(line.end)?;
+        let one = z3::ast::Real::from_real(self.ctx, 1, 1);
+        let mut free = Vec::new();
+        for (idx, point) in self.points.iter() {
+            for (coord, var) in [(Coord::X, &point.x), (Coord::Y, &point.y)] {
+                let value = model.eval(var, true).ok_or_else(|| {
+                    TextCadError::SolverError(format!("Failed to evaluate {}", var))
+                })?;
+                let shifted = &value + &one;
+
+                let satisfiable_at = |target: &z3::ast::Real<'ctx>| {
+                    self.solver.push();
+                    self.solver.assert(&var._eq(target));
+                    let result = self.solver.check();
+                    self.solver.pop(1);
+                    result == SatResult::Sat
+                };
+
+                if satisfiable_at(&value) && satisfiable_at(&shifted) {
+                    free.push((PointId::from(idx), coord));
+                }
+            }
+        }
 
-            // Extract line parameters
-            solution.extract_line_parameters(line_id, start_coords, end_coords)?;
+        Ok(free)
+    }
+
+    /// Apply all constraints and solve, timing each `Constraint::apply` call
+    ///
+    /// Behaves like `solve_constraints` but also returns how long each
+    /// constraint took to encode, in insertion order. Useful for finding
+    /// constraints that generate expensive assertions.
+    pub fn solve_constraints_timed(
+        &mut self,
+    ) -> Result<(SatResult, Vec<(ConstraintId, Duration)>)> {
+        self.dirty = true;
+        self.clear_applied_constraints();
+        self.solver.push();
+        self.constraints_applied = true;
+        let mut timings = Vec::with_capacity(self.constraints.len());
+
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            let start = Instant::now();
+            constraint.apply(self.ctx, &self.solver, self)?;
+            timings.push((ConstraintId(index), start.elapsed()));
         }
 
-        // Extract parameters for all circles
-        for (idx, circle) in self.circles.iter() {
-            let circle_id = CircleId::from(idx);
+        let result = self.solve()?;
+        Ok((result, timings))
+    }
 
-            // Get center point coordinates
-            let center_coords = solution.get_point_coordinates(circle.center)?;
+    /// Find single constraints whose removal makes an unsatisfiable sketch solvable
+    ///
+    /// Drops one constraint at a time, re-solves the rest, and measures how far
+    /// the resulting model is from satisfying the dropped constraint. Returns the
+    /// resolving removals with residual above `tol`, largest residual first. An
+    /// empty vector means the sketch is satisfiable as is (or no single removal
+    /// helps). Constraints are applied inside a solver push/pop scope, so the
+    /// solver is left unchanged.
+    ///
+    /// # Arguments
+    /// * `tol` - Residuals at or below this value are treated as satisfied
+    pub fn relax_and_diagnose(&mut self, tol: f64) -> Result<Vec<(ConstraintId, f64)>> {
+        self.dirty = true;
+        self.clear_applied_constraints();
+        if tol.is_nan() || tol < 0.0 {
+            return Err(TextCadError::InvalidParameter(format!(
+                "Tolerance must be non-negative, got {}",
+                tol
+            )));
+        }
 
-            // Extract circle parameters
-            solution.extract_circle_parameters(circle_id, center_coords, &circle.radius)?;
+        if self.check_without(None)?.is_some() {
+            return Ok(Vec::new());
         }
 
-        Ok(solution)
+        let mut resolving = Vec::new();
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            if let Some(model) = self.check_without(Some(i))? {
+                let residual = self.constraint_residual(constraint.as_ref(), &model)?;
+                if residual > tol {
+                    resolving.push((ConstraintId(i), residual));
+                }
+            }
+        }
+
+        resolving.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(resolving)
     }
-}
 
-impl<'ctx> SketchQuery for Sketch<'ctx> {
-    fn point_variables(&self, point_id: PointId) -> Result<(z3::ast::Real<'_>, z3::ast::Real<'_>)> {
-        if let Some(point) = self.get_point(point_id) {
-            Ok((point.x.clone(), point.y.clone()))
-        } else {
-            Err(TextCadError::EntityError(format!(
-                "Point {:?} not found",
-                point_id
-            )))
+    /// Find a minimal set of constraints that is unsatisfiable on its own
+    ///
+    /// Uses delta debugging (ddmin): the candidate set starts as all
+    /// constraints and is repeatedly narrowed to a chunk, or to the
+    /// complement of a chunk, that is still unsatisfiable, refining the
+    /// chunk size when neither works. The result is 1-minimal: dropping any
+    /// one of the returned constraints makes the rest satisfiable. This works
+    /// by experiment on the sketch's own constraints, complementing Z3's
+    /// unsat cores. Returns an empty vector if the sketch is satisfiable.
+    /// Assertions left on the solver by an earlier solve are dropped first,
+    /// and all checks happen in temporary solver scopes.
+    pub fn minimal_conflict_set(&mut self) -> Result<Vec<ConstraintId>> {
+        self.dirty = true;
+        self.clear_applied_constraints();
+        let mut core: Vec<usize> = (0..self.constraints.len()).collect();
+        if self.is_subset_satisfiable(&core)? {
+            return Ok(Vec::new());
+        }
+
+        let mut granularity = 2;
+        while core.len() >= 2 {
+            let chunk_size = core.len().div_ceil(granularity);
+            let chunks: Vec<Vec<usize>> = core.chunks(chunk_size).map(<[usize]>::to_vec).collect();
+
+            let mut reduced = None;
+            for chunk in &chunks {
+                if !self.is_subset_satisfiable(chunk)? {
+                    reduced = Some((chunk.clone(), 2));
+                    break;
+                }
+            }
+            if reduced.is_none() && chunks.len() > 2 {
+                for chunk in &chunks {
+                    let complement: Vec<usize> = core
+                        .iter()
+                        .copied()
+                        .filter(|i| !chunk.contains(i))
+                        .collect();
+                    if !self.is_subset_satisfiable(&complement)? {
+                        reduced = Some((complement, (granularity - 1).max(2)));
+                        break;
+                    }
+                }
+            }
+
+            match reduced {
+                Some((subset, next_granularity)) => {
+                    core = subset;
+                    granularity = next_granularity;
+                }
+                None if granularity >= core.len() => break,
+                None => granularity = (granularity * 2).min(core.len()),
+            }
         }
+
+        Ok(core.into_iter().map(ConstraintId).collect())
     }
 
-    fn line_endpoints(&self, line_id: LineId) -> Result<(PointId, PointId)> {
-        if let Some(line) = self.get_line(line_id) {
-            Ok((line.start, line.end))
-        } else {
-            Err(TextCadError::EntityError(format!(
-                "Line {:?} not found",
-                line_id
-            )))
+    /// Apply only the constraints at `indices` in a temporary scope and check satisfiability
+    fn is_subset_satisfiable(&self, indices: &[usize]) -> Result<bool> {
+        self.solver.push();
+        let applied = indices
+            .iter()
+            .try_for_each(|&i| self.constraints[i].apply(self.ctx, &self.solver, self));
+        let result = applied.map(|_| self.solver.check());
+        self.solver.pop(1);
+
+        match result? {
+            SatResult::Sat => Ok(true),
+            SatResult::Unsat => Ok(false),
+            SatResult::Unknown => Err(TextCadError::SolverError(
+                "Z3 solver returned unknown result".to_string(),
+            )),
         }
     }
 
-    fn circle_center_and_radius(
+    /// Apply all constraints except `skip` in a temporary scope and return a model if satisfiable
+    fn check_without(&self, skip: Option<usize>) -> Result<Option<Model<'ctx>>> {
+        self.solver.push();
+        let applied = self
+            .constraints
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| Some(*j) != skip)
+            .try_for_each(|(_, constraint)| constraint.apply(self.ctx, &self.solver, self));
+        let model = match applied {
+            Ok(()) if self.solver.check() == SatResult::Sat => self.solver.get_model(),
+            _ => None,
+        };
+        self.solver.pop(1);
+        applied.map(|_| model)
+    }
+
+    /// Describe each constraint and its residual under the given model
+    ///
+    /// Constraints that fail to apply report an infinite residual.
+    pub(crate) fn constraint_residuals(&self, model: &Model<'ctx>) -> Vec<(String, f64)> {
+        self.constraints
+            .iter()
+            .map(|constraint| {
+                let residual = self
+                    .constraint_residual(constraint.as_ref(), model)
+                    .unwrap_or(f64::INFINITY);
+                (constraint.description(), residual)
+            })
+            .collect()
+    }
+
+    /// Largest violation of a constraint's assertions under the given model
+    fn constraint_residual(&self, constraint: &dyn Constraint, model: &Model<'ctx>) -> Result<f64> {
+        // Apply to a scratch solver to get the constraint's assertions in isolation
+        let scratch = Solver::new(self.ctx);
+        constraint.apply(self.ctx, &scratch, self)?;
+
+        Ok(scratch
+            .get_assertions()
+            .iter()
+            .map(|assertion| assertion_residual(assertion, model))
+            .fold(0.0, f64::max))
+    }
+
+    /// Whether the sketch has no entities and no constraints
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+            && self.lines.is_empty()
+            && self.circles.is_empty()
+            && self.arcs.is_empty()
+            && self.ellipses.is_empty()
+            && self.constraints.is_empty()
+    }
+
+    /// Apply all constraints, solve, and return a Solution with extracted coordinates
+    ///
+    /// A sketch with no entities, constraints, or direct solver assertions
+    /// short-circuits to an empty solution without running the solver.
+    ///
+    /// If nothing changed since the last successful call, the constraints are
+    /// not re-applied and the solver is not re-run; the cached model is
+    /// extracted again instead. Adding entities or constraints, touching the
+    /// solver directly, or running another solve/diagnosis method invalidates
+    /// the cache. Use `force_resolve` to bypass it.
+    pub fn solve_and_extract(&mut self) -> Result<Solution<'ctx>> {
+        if self.is_empty() && self.solver.get_assertions().is_empty() {
+            // Nothing to solve: extract from the trivially satisfiable empty model
+            let scratch = Solver::new(self.ctx);
+            scratch.check();
+            let model = scratch.get_model().ok_or_else(|| {
+                TextCadError::SolverError("No model available for empty sketch".to_string())
+            })?;
+            return self.extract_solution(model);
+        }
+
+        if self.dirty {
+            // Apply all constraints and solve
+            let start = Instant::now();
+            self.solve_constraints()?;
+            self.last_solve_time = Some(start.elapsed());
+            self.solve_count += 1;
+            self.dirty = false;
+        }
+
+        // Extract the model
+        let model = self.solver.get_model().ok_or_else(|| {
+            TextCadError::SolverError("No model available after solving".to_string())
+        })?;
+
+        self.extract_solution(model)
+    }
+
+    /// Solve and extract, ignoring any cached result from a previous solve
+    pub fn force_resolve(&mut self) -> Result<Solution<'ctx>> {
+        self.dirty = true;
+        self.solve_and_extract()
+    }
+
+    /// Number of times `solve_and_extract` ran the solver rather than reusing the cache
+    pub fn solve_count(&self) -> usize {
+        self.solve_count
+    }
+
+    /// Wall-clock time of the last solve run by `solve_and_extract`, if any
+    pub fn last_solve_time(&self) -> Option<Duration> {
+        self.last_solve_time
+    }
+
+    /// Apply all constraints and solve, preferring configurations near `previous`
+    ///
+    /// Each point present in `previous` gets soft equality assertions tying its
+    /// coordinates to the old values, so an under-constrained sketch stays close
+    /// to its last answer after a small edit. The soft assertions are
+    /// best-effort: any that conflict with the constraints are dropped. Solving
+    /// uses a separate Z3 optimizer, leaving the sketch's solver unchanged; a
+    /// `timeout` set through `set_solver_param` applies to it as well.
+    pub fn solve_warm(&mut self, previous: &Solution) -> Result<Solution<'ctx>> {
+        self.dirty = true;
+        self.clear_applied_constraints();
+        // Collect the constraint assertions without keeping them on the solver
+        self.solver.push();
+        let applied = self
+            .constraints
+            .iter()
+            .try_for_each(|constraint| constraint.apply(self.ctx, &self.solver, self));
+        let assertions = self.solver.get_assertions();
+        self.solver.pop(1);
+        applied?;
+
+        let optimize = Optimize::new(self.ctx);
+        for assertion in &assertions {
+            optimize.assert(assertion);
+        }
+
+        for (idx, point) in self.points.iter() {
+            if let Ok((x, y)) = previous.get_point_coordinates(PointId::from(idx)) {
+                optimize.assert_soft(&point.x._eq(&real_from_f64(self.ctx, x)?), 1, None);
+                optimize.assert_soft(&point.y._eq(&real_from_f64(self.ctx, y)?), 1, None);
+            }
+        }
+
+        match self.check_optimizer(&optimize) {
+            SatResult::Sat => {}
+            SatResult::Unsat => return Err(TextCadError::OverConstrained),
+            SatResult::Unknown => {
+                return Err(TextCadError::SolverError(
+                    "Z3 optimizer returned unknown result".to_string(),
+                ));
+            }
+        }
+
+        let model = optimize.get_model().ok_or_else(|| {
+            TextCadError::SolverError("No model available after solving".to_string())
+        })?;
+
+        self.extract_solution(model)
+    }
+
+    /// Solve, falling back to the nearest feasible compromise when unsat
+    ///
+    /// A satisfiable sketch solves exactly as `solve_and_extract` would. On
+    /// unsat, every equality between reals among the applied assertions is
+    /// made soft: it is asserted softly with weight `BEST_EFFORT_WEIGHT`, and
+    /// a slack bounding `|lhs - rhs|` is introduced. The optimizer first keeps
+    /// as many equalities as possible, then minimizes the sum of slacks, so
+    /// the dropped equalities are violated as little as possible. Any other
+    /// assertion stays hard. Solving uses a separate Z3 optimizer, leaving the
+    /// sketch's solver unchanged; a `timeout` set through `set_solver_param`
+    /// applies to it as well.
+    pub fn solve_best_effort(&mut self) -> Result<Solution<'ctx>> {
+        const BEST_EFFORT_WEIGHT: u32 = 1000;
+
+        if self.is_satisfiable()? {
+            return self.solve_and_extract();
+        }
+
+        self.solver.push();
+        let applied = self
+            .constraints
+            .iter()
+            .try_for_each(|constraint| constraint.apply(self.ctx, &self.solver, self));
+        let assertions = self.solver.get_assertions();
+        self.solver.pop(1);
+        applied?;
+
+        let optimize = Optimize::new(self.ctx);
+        let zero = Real::from_real(self.ctx, 0, 1);
+        let mut slacks = Vec::new();
+        for (i, assertion) in assertions.iter().enumerate() {
+            let children = assertion.children();
+            if assertion.decl().kind() == DeclKind::EQ
+                && let [lhs, rhs] = children.as_slice()
+                && let (Some(lhs), Some(rhs)) = (lhs.as_real(), rhs.as_real())
+            {
+                // slack >= |lhs - rhs|
+                let diff = (&lhs).sub(&rhs);
+                let slack = Real::new_const(self.ctx, format!("best_effort_slack_{}", i));
+                optimize.assert(&slack.ge(&diff));
+                optimize.assert(&slack.ge(&(&zero).sub(&diff)));
+                optimize.assert_soft(assertion, BEST_EFFORT_WEIGHT, None);
+                slacks.push(slack);
+            } else {
+                optimize.assert(assertion);
+            }
+        }
+
+        if !slacks.is_empty() {
+            let slack_refs: Vec<&Real> = slacks.iter().collect();
+            optimize.minimize(&Real::add(self.ctx, &slack_refs));
+        }
+
+        match self.check_optimizer(&optimize) {
+            SatResult::Sat => {}
+            SatResult::Unsat => return Err(TextCadError::OverConstrained),
+            SatResult::Unknown => {
+                return Err(TextCadError::SolverError(
+                    "Z3 optimizer returned unknown result".to_string(),
+                ));
+            }
+        }
+
+        let model = optimize.get_model().ok_or_else(|| {
+            TextCadError::SolverError("No model available after solving".to_string())
+        })?;
+
+        self.extract_solution(model)
+    }
+
+    /// Build a Solution with coordinates and parameters for every entity
+    fn extract_solution(&self, model: Model<'ctx>) -> Result<Solution<'ctx>> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::span!(
+            tracing::Level::INFO,
+            "extract_solution",
+            points = self.points.len(),
+            lines = self.lines.len(),
+            circles = self.circles.len()
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        // Create solution and extract all point coordinates
+        let mut solution = Solution::new(model);
+
+        // Extract coordinates for all points
+        for (idx, point) in self.points.iter() {
+            let point_id = PointId::from(idx);
+            solution.extract_point_coordinates(point_id, &point.x, &point.y)?;
+        }
+
+        // Extract parameters for all lines
+        for (idx, line) in self.lines.iter() {
+            let line_id = LineId::from(idx);
+
+            // Get start and end point coordinates
+            let start_coords = solution.get_point_coordinates(line.start)?;
+            let end_coords = solution.get_point_coordinates(line.end)?;
+
+            // Extract line parameters
+            solution.extract_line_parameters(line_id, start_coords, end_coords)?;
+        }
+
+        // Extract parameters for all circles
+        for (idx, circle) in self.circles.iter() {
+            let circle_id = CircleId::from(idx);
+
+            // Get center point coordinates
+            let center_coords = solution.get_point_coordinates(circle.center)?;
+
+            // Extract circle parameters
+            solution.extract_circle_parameters(circle_id, center_coords, &circle.radius)?;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::event!(
+            tracing::Level::INFO,
+            elapsed_us = start.elapsed().as_micros() as u64,
+            "solution extracted"
+        );
+
+        Ok(solution)
+    }
+}
+
+/// Numeric violation of a single assertion under a model
+///
+/// Equalities and inequalities between reals report how far they are from
+/// holding; any other assertion reports 0.0 if it holds and infinity otherwise.
+fn assertion_residual(assertion: &Bool<'_>, model: &Model<'_>) -> f64 {
+    let children = assertion.children();
+    if let [lhs, rhs] = children.as_slice()
+        && let (Some(lhs), Some(rhs)) = (lhs.as_real(), rhs.as_real())
+        && let Some(diff) = model
+            .eval(&(&lhs).sub(&rhs), true)
+            .and_then(|value| match value.as_real() {
+                Some((num, den)) => Some(num as f64 / den as f64),
+                None => large_rational_to_f64(&value),
+            })
+    {
+        match assertion.decl().kind() {
+            DeclKind::EQ => return diff.abs(),
+            DeclKind::GE | DeclKind::GT => return (-diff).max(0.0),
+            DeclKind::LE | DeclKind::LT => return diff.max(0.0),
+            _ => {}
+        }
+    }
+
+    match model
+        .eval(assertion, true)
+        .and_then(|value| value.as_bool())
+    {
+        Some(true) => 0.0,
+        _ => f64::INFINITY,
+    }
+}
+
+impl<'ctx> SketchQuery for Sketch<'ctx> {
+    fn point_variables(&self, point_id: PointId) -> Result<(z3::ast::Real<'_>, z3::ast::Real<'_>)> {
+        if let Some(point) = self.get_point(point_id) {
+            let (x, y) = point.variables();
+            Ok((x.clone(), y.clone()))
+        } else {
+            Err(TextCadError::EntityError(format!(
+                "Point {:?} not found",
+                point_id
+            )))
+        }
+    }
+
+    fn line_endpoints(&self, line_id: LineId) -> Result<(PointId, PointId)> {
+        if let Some(line) = self.get_line(line_id) {
+            Ok((line.start, line.end))
+        } else {
+            Err(TextCadError::EntityError(format!(
+                "Line {:?} not found",
+                line_id
+            )))
+        }
+    }
+
+    fn circle_center_and_radius(
         &self,
         circle_id: CircleId,
     ) -> Result<(PointId, z3::ast::Real<'_>)> {
@@ -363,6 +1625,46 @@ impl<'ctx> SketchQuery for Sketch<'ctx> {
         }
     }
 
+    fn arc_circle_and_endpoints(&self, arc_id: ArcId) -> Result<(CircleId, PointId, PointId)> {
+        if let Some(arc) = self.get_arc(arc_id) {
+            Ok((arc.circle, arc.start, arc.end))
+        } else {
+            Err(TextCadError::EntityError(format!(
+                "Arc {:?} not found",
+                arc_id
+            )))
+        }
+    }
+
+    fn arc_angle_variables(&self, arc_id: ArcId) -> Result<(z3::ast::Real<'_>, z3::ast::Real<'_>)> {
+        if let Some(arc) = self.get_arc(arc_id) {
+            Ok((arc.start_angle.clone(), arc.end_angle.clone()))
+        } else {
+            Err(TextCadError::EntityError(format!(
+                "Arc {:?} not found",
+                arc_id
+            )))
+        }
+    }
+
+    fn ellipse_center_and_axes(
+        &self,
+        ellipse_id: EllipseId,
+    ) -> Result<(PointId, z3::ast::Real<'_>, z3::ast::Real<'_>)> {
+        if let Some(ellipse) = self.get_ellipse(ellipse_id) {
+            Ok((
+                ellipse.center,
+                ellipse.semi_major.clone(),
+                ellipse.semi_minor.clone(),
+            ))
+        } else {
+            Err(TextCadError::EntityError(format!(
+                "Ellipse {:?} not found",
+                ellipse_id
+            )))
+        }
+    }
+
     fn length_variable(&self, name: &str) -> Result<z3::ast::Real<'_>> {
         // For now, create a new length variable on demand
         Ok(z3::ast::Real::new_const(
@@ -567,91 +1869,690 @@ mod tests {
         use generational_arena::Index;
         let fake_id = PointId::from(Index::from_raw_parts(999, 999));
 
-        assert!(sketch.get_point(fake_id).is_none());
+        assert!(sketch.get_point(fake_id).is_none());
+    }
+
+    // Integration tests for constraint solving workflow
+    #[test]
+    fn test_single_point_fixed_position() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        // Add a point and fix it at a specific position
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let constraint = crate::constraints::FixedPositionConstraint::new(
+            p1,
+            crate::units::Length::meters(3.0),
+            crate::units::Length::meters(4.0),
+        );
+        sketch.add_constraint(constraint);
+
+        // Solve and extract solution
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x, y) = solution.get_point_coordinates(p1).unwrap();
+
+        assert!((x - 3.0).abs() < 1e-6);
+        assert!((y - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_coincident_points_constraint() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        // Add two points and make them coincident
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+
+        // Fix one point's position
+        let fix_constraint = crate::constraints::FixedPositionConstraint::new(
+            p1,
+            crate::units::Length::meters(1.0),
+            crate::units::Length::meters(2.0),
+        );
+        sketch.add_constraint(fix_constraint);
+
+        // Make the second point coincident with the first
+        let coincident_constraint = crate::constraints::CoincidentPointsConstraint::new(p1, p2);
+        sketch.add_constraint(coincident_constraint);
+
+        // Solve and verify both points have the same coordinates
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x1, y1) = solution.get_point_coordinates(p1).unwrap();
+        let (x2, y2) = solution.get_point_coordinates(p2).unwrap();
+
+        assert!((x1 - 1.0).abs() < 1e-6);
+        assert!((y1 - 2.0).abs() < 1e-6);
+        assert!((x1 - x2).abs() < 1e-6);
+        assert!((y1 - y2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_overconstrainted_system() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        // Add a point
+        let p1 = sketch.add_point(Some("p1".to_string()));
+
+        // Try to fix it at two different positions (overconstraint)
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            crate::units::Length::meters(1.0),
+            crate::units::Length::meters(1.0),
+        ));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            crate::units::Length::meters(2.0),
+            crate::units::Length::meters(2.0),
+        ));
+
+        // This should fail as the system is overconstrained
+        let result = sketch.solve_and_extract();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), TextCadError::OverConstrained));
+    }
+
+    #[test]
+    fn test_add_rectangle() {
+        use crate::constraints::FixedPositionConstraint;
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let origin = sketch.add_point(Some("origin".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            origin,
+            Length::meters(1.0),
+            Length::meters(2.0),
+        ));
+        let (corners, lines) =
+            sketch.add_rectangle(origin, Length::meters(4.0), Length::meters(3.0));
+        assert_eq!(corners[0], origin);
+
+        let solution = sketch.solve_and_extract().unwrap();
+
+        let coords: Vec<(f64, f64)> = corners
+            .iter()
+            .map(|&p| solution.get_point_coordinates(p).unwrap())
+            .collect();
+        assert!((coords[0].0 - 1.0).abs() < 1e-6);
+        assert!((coords[0].1 - 2.0).abs() < 1e-6);
+
+        // Every corner is a right angle
+        for i in 0..4 {
+            let prev = coords[(i + 3) % 4];
+            let here = coords[i];
+            let next = coords[(i + 1) % 4];
+            let dot = (prev.0 - here.0) * (next.0 - here.0) + (prev.1 - here.1) * (next.1 - here.1);
+            assert!(dot.abs() < 1e-6, "corner {} is not square", i);
+        }
+
+        // Sides alternate between width and height
+        let expected = [4.0, 3.0, 4.0, 3.0];
+        for (line, length) in lines.iter().zip(expected) {
+            let params = solution.get_line_parameters(*line).unwrap();
+            assert!((params.length - length).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_add_rectangle_on_unnamed_corner() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let corner = sketch.add_point(None);
+        sketch.fix_points(&[(corner, Length::meters(-1.0), Length::meters(0.5))]);
+        let (corners, _) = sketch.add_rectangle(corner, Length::meters(2.0), Length::meters(1.0));
+
+        let solution = sketch.solve_and_extract().unwrap();
+        let coords: Vec<(f64, f64)> = corners
+            .iter()
+            .map(|&p| solution.get_point_coordinates(p).unwrap())
+            .collect();
+
+        // Four distinct corners: both diagonals span sqrt(2² + 1²)
+        let diagonal =
+            |a: (f64, f64), b: (f64, f64)| ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+        assert!((diagonal(coords[0], coords[2]) - 5f64.sqrt()).abs() < 1e-6);
+        assert!((diagonal(coords[1], coords[3]) - 5f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_solve_and_extract_reuses_cache_until_changed() {
+        use crate::constraints::FixedPositionConstraint;
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p1,
+            Length::meters(1.0),
+            Length::meters(2.0),
+        ));
+        assert_eq!(sketch.solve_count(), 0);
+        assert!(sketch.last_solve_time().is_none());
+
+        let first = sketch.solve_and_extract().unwrap();
+        let second = sketch.solve_and_extract().unwrap();
+        assert_eq!(sketch.solve_count(), 1);
+        assert!(sketch.last_solve_time().is_some());
+        assert!(first.approx_eq(&second, 0.0));
+
+        // Adding a constraint invalidates the cache
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p2,
+            Length::meters(3.0),
+            Length::meters(4.0),
+        ));
+        let third = sketch.solve_and_extract().unwrap();
+        assert_eq!(sketch.solve_count(), 2);
+        assert_eq!(third.get_point_coordinates(p2).unwrap(), (3.0, 4.0));
+
+        // force_resolve always runs the solver
+        sketch.force_resolve().unwrap();
+        assert_eq!(sketch.solve_count(), 3);
+    }
+
+    #[test]
+    fn test_is_satisfiable() {
+        use crate::constraints::FixedPositionConstraint;
+        use crate::units::Length;
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p1,
+            Length::meters(1.0),
+            Length::meters(1.0),
+        ));
+        assert!(sketch.is_satisfiable().unwrap());
+
+        // Pinning the same point elsewhere over-constrains the sketch
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p1,
+            Length::meters(2.0),
+            Length::meters(2.0),
+        ));
+        assert!(!sketch.is_satisfiable().unwrap());
+
+        // The checks leave no assertions behind on the solver
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+    }
+
+    #[test]
+    fn test_free_coordinates_point_fixed_in_x() {
+        use crate::constraints::{FixedPositionConstraint, HorizontalDistanceConstraint};
+        use crate::units::Length;
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        // Anchor is pinned; p only has its x offset from the anchor fixed
+        let anchor = sketch.add_point(Some("anchor".to_string()));
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            anchor,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(HorizontalDistanceConstraint::new(
+            anchor,
+            p,
+            Length::meters(3.0),
+        ));
+
+        let free = sketch.free_coordinates().unwrap();
+
+        assert_eq!(free, vec![(p, Coord::Y)]);
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+    }
+
+    #[test]
+    fn test_solve_constraints_timed() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let line = sketch.add_line(p1, p2, Some("line".to_string()));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(crate::constraints::LineLengthConstraint::new(
+            line,
+            Length::meters(3.0),
+        ));
+        sketch.add_midpoint(line, Some("mid".to_string()));
+
+        let (result, timings) = sketch.solve_constraints_timed().unwrap();
+
+        assert_eq!(result, SatResult::Sat);
+        assert_eq!(timings.len(), 3);
+        for (i, (id, duration)) in timings.iter().enumerate() {
+            assert_eq!(*id, ConstraintId(i));
+            assert!(*duration >= Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_solve_warm_stays_near_previous_solution() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let line = sketch.add_line(p1, p2, Some("line".to_string()));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(crate::constraints::LineLengthConstraint::new(
+            line,
+            Length::meters(5.0),
+        ));
+        // Leaves only the sign of p2's y coordinate free
+        sketch.add_constraint(crate::constraints::HorizontalDistanceConstraint::new(
+            p1,
+            p2,
+            Length::meters(3.0),
+        ));
+
+        let first = sketch.solve_and_extract().unwrap();
+        let warm = sketch.solve_warm(&first).unwrap();
+
+        // Constraints still hold
+        let params = warm.get_line_parameters(line).unwrap();
+        assert!((params.length - 5.0).abs() < 1e-6);
+
+        // The free endpoint stays where it was
+        let (x0, y0) = first.get_point_coordinates(p2).unwrap();
+        let (x1, y1) = warm.get_point_coordinates(p2).unwrap();
+        assert!((x1 - x0).abs() < 1e-6);
+        assert!((y1 - y0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_solve_warm_applies_solver_timeout() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.fix_points(&[(p, Length::meters(2.0), Length::meters(-1.0))]);
+        sketch.set_solver_param("timeout", SolverParamValue::U32(5_000));
+        sketch.set_solver_param("auto_config", SolverParamValue::Bool(false));
+        sketch.set_solver_param("timeout", SolverParamValue::U32(20_000));
+        assert_eq!(sketch.solver_timeout_ms(), Some(20_000));
+
+        let first = sketch.solve_and_extract().unwrap();
+        let warm = sketch.solve_warm(&first).unwrap();
+        assert_eq!(warm.get_point_coordinates(p).unwrap(), (2.0, -1.0));
+    }
+
+    #[test]
+    fn test_check_optimizer_interrupts_after_timeout() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+        sketch.set_solver_param("timeout", SolverParamValue::U32(100));
+
+        // x^3 + y^3 = z^3 has no solution Z3 can find or refute quickly
+        let optimize = Optimize::new(&ctx);
+        let one = z3::ast::Int::from_i64(&ctx, 1);
+        let cube = |name: &str| {
+            let v = z3::ast::Int::new_const(&ctx, name);
+            optimize.assert(&v.gt(&one));
+            z3::ast::Int::mul(&ctx, &[&v, &v, &v])
+        };
+        let (x3, y3, z3_cube) = (cube("x"), cube("y"), cube("z"));
+        optimize.assert(&z3::ast::Int::add(&ctx, &[&x3, &y3])._eq(&z3_cube));
+
+        let start = Instant::now();
+        assert_eq!(sketch.check_optimizer(&optimize), SatResult::Unknown);
+        assert!(start.elapsed() < Duration::from_secs(10));
+
+        // The interrupt does not leave the context unusable
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.fix_points(&[(p, Length::meters(1.0), Length::meters(1.0))]);
+        let first = sketch.solve_and_extract().unwrap();
+        assert!(sketch.solve_warm(&first).is_ok());
+    }
+
+    #[test]
+    fn test_relax_and_diagnose_conflicting_fixed_positions() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+
+        let first = sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            crate::units::Length::meters(1.0),
+            crate::units::Length::meters(1.0),
+        ));
+        let second = sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            crate::units::Length::meters(1.0),
+            crate::units::Length::meters(3.0),
+        ));
+
+        let diagnosis = sketch.relax_and_diagnose(1e-9).unwrap();
+
+        // Removing either fixed position resolves the conflict, with residual |3 - 1|
+        assert_eq!(diagnosis.len(), 2);
+        assert!(diagnosis.iter().any(|(id, _)| *id == first));
+        assert!(diagnosis.iter().any(|(id, _)| *id == second));
+        for (_, residual) in &diagnosis {
+            assert!((residual - 2.0).abs() < 1e-6);
+        }
+
+        // The solver itself is left untouched
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+    }
+
+    #[test]
+    fn test_relax_and_diagnose_after_failed_solve() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        let q = sketch.add_point(Some("q".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            q,
+            Length::meters(5.0),
+            Length::meters(5.0),
+        ));
+        let first = sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        let second = sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(0.0),
+            Length::meters(2.0),
+        ));
+
+        assert!(matches!(
+            sketch.solve_and_extract(),
+            Err(TextCadError::OverConstrained)
+        ));
+
+        // The failed solve's assertions must not leak into the probes
+        let diagnosis = sketch.relax_and_diagnose(1e-9).unwrap();
+        let mut ids: Vec<ConstraintId> = diagnosis.iter().map(|(id, _)| *id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![first, second]);
+    }
+
+    #[test]
+    fn test_solver_mut_assertions_survive_resolving() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.fix_points(&[(p, Length::meters(1.0), Length::meters(1.0))]);
+        sketch.solve_and_extract().unwrap();
+
+        // A direct assertion added after a solve stays for later solves
+        let p_x = sketch.get_point(p).unwrap().x.clone();
+        sketch
+            .solver_mut()
+            .assert(&p_x._eq(&Real::from_real(&ctx, 2, 1)));
+        assert!(sketch.force_resolve().is_err());
+        assert!(sketch.force_resolve().is_err());
+    }
+
+    #[test]
+    fn test_minimal_conflict_set_finds_conflicting_pair() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        let q = sketch.add_point(Some("q".to_string()));
+
+        let first = sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            q,
+            Length::meters(2.0),
+            Length::meters(2.0),
+        ));
+        let third = sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(1.0),
+            Length::meters(0.0),
+        ));
+
+        assert_eq!(sketch.minimal_conflict_set().unwrap(), vec![first, third]);
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+    }
+
+    #[test]
+    fn test_relax_and_diagnose_satisfiable_sketch() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            crate::units::Length::meters(1.0),
+            crate::units::Length::meters(1.0),
+        ));
+
+        assert!(sketch.relax_and_diagnose(1e-9).unwrap().is_empty());
+        assert!(matches!(
+            sketch.relax_and_diagnose(-1.0),
+            Err(TextCadError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_origin_is_stable_and_solves_to_zero() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let origin = sketch.origin();
+        assert_eq!(sketch.origin(), origin);
+        assert_eq!(sketch.get_point(origin).unwrap().display_name(), "origin");
+
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x, y) = solution.get_point_coordinates(origin).unwrap();
+        assert!(x.abs() < 1e-10);
+        assert!(y.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_assertions_as_strings_perpendicular() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("a".to_string()));
+        let p2 = sketch.add_point(Some("b".to_string()));
+        let p3 = sketch.add_point(Some("c".to_string()));
+        let line1 = sketch.add_line(p1, p2, None);
+        let line2 = sketch.add_line(p2, p3, None);
+
+        let constraint = crate::constraints::PerpendicularLinesConstraint::new(line1, line2);
+        constraint
+            .apply(sketch.context(), sketch.solver(), &sketch)
+            .unwrap();
+
+        let assertions = sketch.assertions_as_strings();
+        assert_eq!(assertions.len(), 1);
+        assert!(assertions[0].contains("a_x"));
+        assert!(assertions[0].contains("c_y"));
+        assert!(assertions[0].contains('*'));
+    }
+
+    #[test]
+    fn test_add_midpoint_on_fixed_line() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let line = sketch.add_line(p1, p2, Some("line".to_string()));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p1,
+            Length::meters(1.0),
+            Length::meters(2.0),
+        ));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p2,
+            Length::meters(5.0),
+            Length::meters(-4.0),
+        ));
+
+        let mid = sketch.add_midpoint(line, Some("mid".to_string()));
+
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x, y) = solution.get_point_coordinates(mid).unwrap();
+        assert!((x - 3.0).abs() < 1e-10);
+        assert!((y + 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_try_add_line_validates_endpoints() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let fake = PointId(generational_arena::Index::from_raw_parts(99, 0));
+
+        let result = sketch.try_add_line(p1, fake, None);
+        assert!(matches!(result, Err(TextCadError::EntityError(_))));
+
+        let line = sketch
+            .try_add_line(p1, p2, Some("line".to_string()))
+            .unwrap();
+        assert_eq!(sketch.get_line(line).unwrap().endpoints(), (p1, p2));
     }
 
-    // Integration tests for constraint solving workflow
     #[test]
-    fn test_single_point_fixed_position() {
+    fn test_remove_point_referenced_by_line() {
         let cfg = Config::new();
         let ctx = Context::new(&cfg);
         let mut sketch = Sketch::new(&ctx);
 
-        // Add a point and fix it at a specific position
         let p1 = sketch.add_point(Some("p1".to_string()));
-        let constraint = crate::constraints::FixedPositionConstraint::new(
-            p1,
-            crate::units::Length::meters(3.0),
-            crate::units::Length::meters(4.0),
-        );
-        sketch.add_constraint(constraint);
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let line = sketch.add_line(p1, p2, Some("line".to_string()));
 
-        // Solve and extract solution
-        let solution = sketch.solve_and_extract().unwrap();
-        let (x, y) = solution.get_point_coordinates(p1).unwrap();
+        let result = sketch.remove_point(p1);
+        assert!(matches!(result, Err(TextCadError::EntityError(_))));
+        assert!(sketch.get_point(p1).is_some());
 
-        assert!((x - 3.0).abs() < 1e-6);
-        assert!((y - 4.0).abs() < 1e-6);
+        // Once the line is gone the point can be removed
+        sketch.remove_line(line).unwrap();
+        assert_eq!(sketch.lines().count(), 0);
+        sketch.remove_point(p1).unwrap();
+        assert!(matches!(
+            sketch.remove_line(line),
+            Err(TextCadError::EntityError(_))
+        ));
     }
 
     #[test]
-    fn test_coincident_points_constraint() {
+    fn test_remove_unused_point() {
         let cfg = Config::new();
         let ctx = Context::new(&cfg);
         let mut sketch = Sketch::new(&ctx);
 
-        // Add two points and make them coincident
         let p1 = sketch.add_point(Some("p1".to_string()));
         let p2 = sketch.add_point(Some("p2".to_string()));
 
-        // Fix one point's position
-        let fix_constraint = crate::constraints::FixedPositionConstraint::new(
-            p1,
-            crate::units::Length::meters(1.0),
-            crate::units::Length::meters(2.0),
-        );
-        sketch.add_constraint(fix_constraint);
+        sketch.remove_point(p1).unwrap();
 
-        // Make the second point coincident with the first
-        let coincident_constraint = crate::constraints::CoincidentPointsConstraint::new(p1, p2);
-        sketch.add_constraint(coincident_constraint);
+        let remaining: Vec<PointId> = sketch.points().map(|(id, _)| id).collect();
+        assert_eq!(remaining, vec![p2]);
+        assert!(sketch.get_point(p1).is_none());
+        assert!(matches!(
+            sketch.remove_point(p1),
+            Err(TextCadError::EntityError(_))
+        ));
+    }
 
-        // Solve and verify both points have the same coordinates
-        let solution = sketch.solve_and_extract().unwrap();
-        let (x1, y1) = solution.get_point_coordinates(p1).unwrap();
-        let (x2, y2) = solution.get_point_coordinates(p2).unwrap();
+    #[test]
+    fn test_remove_point_referenced_by_arc_ellipse_or_origin() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
 
-        assert!((x1 - 1.0).abs() < 1e-6);
-        assert!((y1 - 2.0).abs() < 1e-6);
-        assert!((x1 - x2).abs() < 1e-6);
-        assert!((y1 - y2).abs() < 1e-6);
+        let center = sketch.add_point(Some("center".to_string()));
+        let start = sketch.add_point(Some("start".to_string()));
+        let end = sketch.add_point(Some("end".to_string()));
+        let circle = sketch.add_circle(center, None);
+        sketch.add_arc(circle, start, end, None);
+        let focus = sketch.add_point(Some("focus".to_string()));
+        sketch.add_ellipse(focus, None);
+        let origin = sketch.origin();
+
+        for p in [start, end, focus, origin] {
+            assert!(matches!(
+                sketch.remove_point(p),
+                Err(TextCadError::EntityError(_))
+            ));
+            assert!(sketch.get_point(p).is_some());
+        }
+
+        // The origin is still pinned for later solves
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x, y) = solution.get_point_coordinates(origin).unwrap();
+        assert!(x.abs() < 1e-10 && y.abs() < 1e-10);
     }
 
     #[test]
-    fn test_overconstrainted_system() {
+    fn test_set_solver_param_auto_config_off() {
         let cfg = Config::new();
         let ctx = Context::new(&cfg);
         let mut sketch = Sketch::new(&ctx);
 
-        // Add a point
-        let p1 = sketch.add_point(Some("p1".to_string()));
+        sketch.set_solver_param("auto_config", SolverParamValue::Bool(false));
+        sketch.set_solver_param("timeout", SolverParamValue::U32(10_000));
 
-        // Try to fix it at two different positions (overconstraint)
-        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
-            p1,
-            crate::units::Length::meters(1.0),
-            crate::units::Length::meters(1.0),
-        ));
+        let p = sketch.add_point(Some("p".to_string()));
         sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
-            p1,
-            crate::units::Length::meters(2.0),
-            crate::units::Length::meters(2.0),
+            p,
+            Length::meters(1.0),
+            Length::meters(2.0),
         ));
 
-        // This should fail as the system is overconstrained
-        let result = sketch.solve_and_extract();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), TextCadError::OverConstrained));
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x, y) = solution.get_point_coordinates(p).unwrap();
+        assert!((x - 1.0).abs() < 1e-10);
+        assert!((y - 2.0).abs() < 1e-10);
     }
 
     // Tests for Line entity functionality
@@ -1130,7 +3031,7 @@ mod tests {
         let circle = sketch.get_circle(circle_id).unwrap();
         assert_eq!(circle.name, None);
         assert!(circle.display_name().starts_with("Circle"));
-        assert!(circle.radius.to_string().contains("c_radius"));
+        assert!(circle.radius.to_string().contains("c0_0_radius"));
     }
 
     #[test]
@@ -1154,4 +3055,492 @@ mod tests {
         // Verify sketch contains both circles
         assert_eq!(sketch.circles.len(), 2);
     }
+
+    #[test]
+    fn test_visit_constraints_tallies_rectangle() {
+        use crate::constraints::{
+            FixedPositionConstraint, LineLengthConstraint, ParallelLinesConstraint,
+            PerpendicularLinesConstraint,
+        };
+        use crate::units::Length;
+        use std::collections::HashMap;
+
+        #[derive(Default)]
+        struct TallyVisitor {
+            counts: HashMap<&'static str, usize>,
+        }
+
+        impl ConstraintVisitor for TallyVisitor {
+            fn visit_fixed(&mut self, _constraint: &FixedPositionConstraint) {
+                *self.counts.entry("fixed").or_default() += 1;
+            }
+
+            fn visit_line_length(&mut self, _constraint: &LineLengthConstraint) {
+                *self.counts.entry("line_length").or_default() += 1;
+            }
+
+            fn visit_parallel(&mut self, _constraint: &ParallelLinesConstraint) {
+                *self.counts.entry("parallel").or_default() += 1;
+            }
+
+            fn visit_perpendicular(&mut self, _constraint: &PerpendicularLinesConstraint) {
+                *self.counts.entry("perpendicular").or_default() += 1;
+            }
+        }
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        // Rectangle: one fixed corner, two side lengths, opposite sides parallel,
+        // and one right angle
+        let a = sketch.add_point(Some("a".to_string()));
+        let b = sketch.add_point(Some("b".to_string()));
+        let c = sketch.add_point(Some("c".to_string()));
+        let d = sketch.add_point(Some("d".to_string()));
+        let ab = sketch.add_line(a, b, None);
+        let bc = sketch.add_line(b, c, None);
+        let cd = sketch.add_line(c, d, None);
+        let da = sketch.add_line(d, a, None);
+
+        sketch.add_constraint(FixedPositionConstraint::new(
+            a,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(LineLengthConstraint::new(ab, Length::meters(4.0)));
+        sketch.add_constraint(LineLengthConstraint::new(bc, Length::meters(3.0)));
+        sketch.add_constraint(ParallelLinesConstraint::new(ab, cd));
+        sketch.add_constraint(ParallelLinesConstraint::new(bc, da));
+        sketch.add_constraint(PerpendicularLinesConstraint::new(ab, bc));
+
+        let mut visitor = TallyVisitor::default();
+        sketch.visit_constraints(&mut visitor);
+
+        assert_eq!(visitor.counts.get("fixed"), Some(&1));
+        assert_eq!(visitor.counts.get("line_length"), Some(&2));
+        assert_eq!(visitor.counts.get("parallel"), Some(&2));
+        assert_eq!(visitor.counts.get("perpendicular"), Some(&1));
+        assert_eq!(visitor.counts.values().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn test_constraint_graph_parallel_lines() {
+        use crate::constraints::ParallelLinesConstraint;
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let a = sketch.add_point(None);
+        let b = sketch.add_point(None);
+        let c = sketch.add_point(None);
+        let d = sketch.add_point(None);
+        let ab = sketch.add_line(a, b, None);
+        let cd = sketch.add_line(c, d, None);
+        let id = sketch.add_constraint(ParallelLinesConstraint::new(ab, cd));
+
+        let constraint = ParallelLinesConstraint::new(ab, cd);
+        assert_eq!(
+            constraint.referenced_entities(),
+            vec![EntityRef::Line(ab), EntityRef::Line(cd)]
+        );
+
+        let graph = sketch.constraint_graph();
+        let entities = graph.entities(id).unwrap();
+        assert_eq!(entities.len(), 6);
+        assert!(entities.contains(&EntityRef::Line(ab)));
+        assert!(entities.contains(&EntityRef::Line(cd)));
+        for p in [a, b, c, d] {
+            assert!(entities.contains(&EntityRef::Point(p)));
+        }
+        assert_eq!(graph.constraints_touching(EntityRef::Point(c)), vec![id]);
+    }
+
+    #[test]
+    fn test_custom_constraint_uses_trait_defaults() {
+        #[derive(Debug)]
+        struct Noop;
+
+        impl Constraint for Noop {
+            fn apply(
+                &self,
+                _context: &z3::Context,
+                _solver: &z3::Solver,
+                _sketch: &dyn SketchQuery,
+            ) -> Result<()> {
+                Ok(())
+            }
+
+            fn description(&self) -> String {
+                "No-op".to_string()
+            }
+        }
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let id = sketch.add_constraint(Noop);
+        assert!(Noop.referenced_entities().is_empty());
+        assert!(sketch.constraint_graph().entities(id).unwrap().is_empty());
+
+        // The default accept routes custom constraints to visit_other
+        #[derive(Default)]
+        struct OtherCounter(Vec<String>);
+
+        impl ConstraintVisitor for OtherCounter {
+            fn visit_other(&mut self, constraint: &dyn Constraint) {
+                self.0.push(constraint.description());
+            }
+        }
+
+        let mut counter = OtherCounter::default();
+        sketch.visit_constraints(&mut counter);
+        assert_eq!(counter.0, vec!["No-op".to_string()]);
+    }
+
+    #[test]
+    fn test_complexity_estimate_linear_rectangle() {
+        use crate::constraints::{HorizontalDistanceConstraint, VerticalDistanceConstraint};
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let corners: Vec<_> = (0..4).map(|_| sketch.add_point(None)).collect();
+        for i in 0..4 {
+            sketch.add_line(corners[i], corners[(i + 1) % 4], None);
+        }
+        sketch.add_constraint(FixedPositionConstraint::new(
+            corners[0],
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        for (a, b, dx, dy) in [(0, 1, 2.0, 0.0), (1, 2, 0.0, 1.0), (0, 3, 0.0, 1.0)] {
+            sketch.add_constraint(HorizontalDistanceConstraint::new(
+                corners[a],
+                corners[b],
+                Length::meters(dx),
+            ));
+            sketch.add_constraint(VerticalDistanceConstraint::new(
+                corners[a],
+                corners[b],
+                Length::meters(dy),
+            ));
+        }
+
+        let estimate = sketch.complexity_estimate();
+        assert_eq!(estimate.linear_constraints, 7);
+        assert_eq!(estimate.nonlinear_constraints, 0);
+        assert_eq!(estimate.variables, 8);
+        assert!(!estimate.likely_hard);
+        assert!(!estimate.nonlinear_dominant());
+    }
+
+    #[test]
+    fn test_complexity_estimate_many_lengths() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let anchor = sketch.add_point(None);
+        sketch.add_constraint(FixedPositionConstraint::new(
+            anchor,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        for _ in 0..12 {
+            let end = sketch.add_point(None);
+            let line = sketch.add_line(anchor, end, None);
+            sketch.add_constraint(LineLengthConstraint::new(line, Length::meters(1.0)));
+        }
+
+        let estimate = sketch.complexity_estimate();
+        assert_eq!(estimate.linear_constraints, 1);
+        assert_eq!(estimate.nonlinear_constraints, 12);
+        assert!(estimate.nonlinear_dominant());
+        assert!(estimate.likely_hard);
+    }
+
+    #[test]
+    fn test_restore_checkpoint_drops_conflicting_constraint() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(1.0),
+            Length::meters(2.0),
+        ));
+        let saved = sketch.checkpoint();
+
+        let q = sketch.add_point(Some("q".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(3.0),
+            Length::meters(2.0),
+        ));
+        assert!(matches!(
+            sketch.solve_and_extract(),
+            Err(TextCadError::OverConstrained)
+        ));
+
+        sketch.restore(saved).unwrap();
+        assert!(sketch.get_point(q).is_none());
+
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x, y) = solution.get_point_coordinates(p).unwrap();
+        assert!((x - 1.0).abs() < 1e-6);
+        assert!((y - 2.0).abs() < 1e-6);
+
+        assert!(sketch.restore(CheckpointId(7)).is_err());
+    }
+
+    #[test]
+    fn test_solve_empty_sketch() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        assert!(sketch.is_empty());
+        let solution = sketch.solve_and_extract().unwrap();
+        assert!(solution.all_point_coordinates().is_empty());
+        assert_eq!(sketch.solve_count(), 0);
+
+        sketch.add_point(None);
+        assert!(!sketch.is_empty());
+    }
+
+    #[test]
+    fn test_solve_best_effort_compromises_on_conflicting_fixes() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p,
+            Length::meters(1.0),
+            Length::meters(2.0),
+        ));
+        sketch.add_constraint(crate::constraints::FixedPositionConstraint::new(
+            p,
+            Length::meters(1.001),
+            Length::meters(2.0),
+        ));
+
+        assert!(!sketch.is_satisfiable().unwrap());
+
+        let solution = sketch.solve_best_effort().unwrap();
+        let (x, y) = solution.get_point_coordinates(p).unwrap();
+
+        // The shared y coordinate still holds exactly
+        assert!((y - 2.0).abs() < 1e-9);
+        // x lands within the gap, off each fix by at most the gap
+        assert!((1.0 - 1e-9..=1.001 + 1e-9).contains(&x));
+        assert!((x - 1.0).abs() + (x - 1.001).abs() <= 0.001 + 1e-9);
+    }
+
+    #[test]
+    fn test_solve_best_effort_with_solver_timeout() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.fix_points(&[
+            (p, Length::meters(0.0), Length::meters(0.0)),
+            (p, Length::meters(0.0), Length::meters(1.0)),
+        ]);
+        sketch.set_solver_param("timeout", SolverParamValue::U32(20_000));
+        sketch.set_solver_param("auto_config", SolverParamValue::Bool(false));
+
+        // The optimizer runs under the timeout watchdog alongside solver-only keys
+        let solution = sketch.solve_best_effort().unwrap();
+        let (x, y) = solution.get_point_coordinates(p).unwrap();
+        assert!(x.abs() < 1e-9);
+        assert!((-1e-9..=1.0 + 1e-9).contains(&y));
+    }
+
+    #[test]
+    fn test_fix_points_pins_every_entry() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let points: Vec<PointId> = (0..3).map(|_| sketch.add_point(None)).collect();
+        let targets = [(1.0, 2.0), (-3.0, 0.5), (0.0, -4.0)];
+        let fixes: Vec<_> = points
+            .iter()
+            .zip(targets)
+            .map(|(&p, (x, y))| (p, Length::meters(x), Length::meters(y)))
+            .collect();
+
+        sketch.fix_points(&fixes);
+        assert_eq!(sketch.constraints.len(), 3);
+
+        let solution = sketch.solve_and_extract().unwrap();
+        for (&p, (x, y)) in points.iter().zip(targets) {
+            let (px, py) = solution.get_point_coordinates(p).unwrap();
+            assert!((px - x).abs() < 1e-6);
+            assert!((py - y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_would_overconstrain_fixed_point() {
+        use crate::constraints::FixedPositionConstraint;
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(1.0),
+            Length::meters(2.0),
+        ));
+
+        let conflicting = FixedPositionConstraint::new(p, Length::meters(3.0), Length::meters(2.0));
+        let redundant = FixedPositionConstraint::new(p, Length::meters(1.0), Length::meters(2.0));
+        assert!(sketch.would_overconstrain(&conflicting).unwrap());
+        assert!(!sketch.would_overconstrain(&redundant).unwrap());
+
+        // Neither probe was kept
+        assert_eq!(sketch.constraints.len(), 1);
+        let solution = sketch.solve_and_extract().unwrap();
+        let (x, _) = solution.get_point_coordinates(p).unwrap();
+        assert!((x - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_available_params_lists_timeout() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let params = sketch.available_params();
+        let timeout = params.iter().find(|p| p.name == "timeout").unwrap();
+        assert_eq!(timeout.kind, SolverParamKind::U32);
+        assert!(matches!(timeout.default, SolverParamValue::U32(_)));
+
+        // Every listed parameter is accepted by the solver
+        for param in params {
+            sketch.set_solver_param(param.name, param.default);
+        }
+        assert!(sketch.solve_and_extract().is_ok());
+    }
+
+    #[test]
+    fn test_minimal_conflict_set_after_failed_solve() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        let q = sketch.add_point(Some("q".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            q,
+            Length::meters(2.0),
+            Length::meters(2.0),
+        ));
+        let second = sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        let third = sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(1.0),
+            Length::meters(0.0),
+        ));
+
+        assert!(sketch.solve_and_extract().is_err());
+        assert_eq!(sketch.minimal_conflict_set().unwrap(), vec![second, third]);
+    }
+
+    #[test]
+    fn test_is_satisfiable_after_failed_solve() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.fix_points(&[(p, Length::meters(1.0), Length::meters(1.0))]);
+        let before_conflict = sketch.checkpoint();
+        sketch.fix_points(&[(p, Length::meters(2.0), Length::meters(1.0))]);
+
+        assert!(sketch.solve_and_extract().is_err());
+        assert!(!sketch.is_satisfiable().unwrap());
+        // The failed solve's assertions were dropped, not just scoped over
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+
+        sketch.restore(before_conflict).unwrap();
+        assert!(sketch.is_satisfiable().unwrap());
+        sketch.solve_and_extract().unwrap();
+        assert!(sketch.is_satisfiable().unwrap());
+    }
+
+    #[test]
+    fn test_free_coordinates_after_solves() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let anchor = sketch.add_point(Some("anchor".to_string()));
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.fix_points(&[(anchor, Length::meters(0.0), Length::meters(0.0))]);
+        sketch.add_constraint(crate::constraints::HorizontalDistanceConstraint::new(
+            anchor,
+            p,
+            Length::meters(3.0),
+        ));
+        let before_conflict = sketch.checkpoint();
+
+        sketch.solve_and_extract().unwrap();
+        assert_eq!(sketch.free_coordinates().unwrap(), vec![(p, Coord::Y)]);
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+
+        // A failed solve reports over-constraint, then recovers after restore
+        sketch.fix_points(&[(anchor, Length::meters(1.0), Length::meters(0.0))]);
+        assert!(sketch.solve_and_extract().is_err());
+        assert!(matches!(
+            sketch.free_coordinates(),
+            Err(TextCadError::OverConstrained)
+        ));
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+
+        sketch.restore(before_conflict).unwrap();
+        assert_eq!(sketch.free_coordinates().unwrap(), vec![(p, Coord::Y)]);
+    }
+
+    #[test]
+    fn test_would_overconstrain_after_failed_solve() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p = sketch.add_point(Some("p".to_string()));
+        sketch.fix_points(&[(p, Length::meters(1.0), Length::meters(2.0))]);
+        let before_conflict = sketch.checkpoint();
+        sketch.fix_points(&[(p, Length::meters(3.0), Length::meters(2.0))]);
+        assert!(sketch.solve_and_extract().is_err());
+
+        let redundant = FixedPositionConstraint::new(p, Length::meters(1.0), Length::meters(2.0));
+        assert!(sketch.would_overconstrain(&redundant).unwrap());
+        assert_eq!(sketch.solver().get_assertions().len(), 0);
+
+        sketch.restore(before_conflict).unwrap();
+        assert!(!sketch.would_overconstrain(&redundant).unwrap());
+
+        // A successful solve leaves nothing behind that skews the probe either
+        sketch.solve_and_extract().unwrap();
+        assert!(!sketch.would_overconstrain(&redundant).unwrap());
+        let conflicting = FixedPositionConstraint::new(p, Length::meters(3.0), Length::meters(2.0));
+        assert!(sketch.would_overconstrain(&conflicting).unwrap());
+    }
 }
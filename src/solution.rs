@@ -4,11 +4,14 @@
 //! coordinates from Z3 models after constraint solving.
 
 use std::collections::HashMap;
-use z3::{Model, ast::Real};
+use z3::{Context, Model, ast::Real};
 
+use crate::constraints::{EllipseSemiAxesConstraint, FixedPositionConstraint};
 use crate::entities::PointId;
 use crate::entity::{CircleId, LineId};
 use crate::error::{Result, TextCadError};
+use crate::sketch::Sketch;
+use crate::units::{Angle, Length};
 
 /// Solution containing extracted coordinates and parameters from a Z3 model
 ///
@@ -21,6 +24,8 @@ pub struct Solution<'ctx> {
     model: Model<'ctx>,
     /// Cached point coordinates extracted from the model (x, y in meters)
     point_coords: HashMap<PointId, (f64, f64)>,
+    /// Z3 variables of each extracted point, for evaluating later expressions
+    point_vars: HashMap<PointId, (Real<'ctx>, Real<'ctx>)>,
     /// Cached line parameters extracted from the model
     line_params: HashMap<LineId, LineParameters>,
     /// Cached circle parameters extracted from the model
@@ -55,6 +60,27 @@ pub struct CircleParameters {
     pub area: f64,
 }
 
+/// Model-free snapshot of all extracted geometry
+///
+/// Owns copies of the cached point, line, and circle values so it can outlive
+/// the Z3 context. Entries are sorted by entity ID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometrySnapshot {
+    /// Point coordinates (x, y in meters)
+    pub points: Vec<(PointId, (f64, f64))>,
+    /// Line parameters
+    pub lines: Vec<(LineId, LineParameters)>,
+    /// Circle parameters
+    pub circles: Vec<(CircleId, CircleParameters)>,
+}
+
+impl LineParameters {
+    /// Get the line angle as a typed `Angle` (from start to end)
+    pub fn angle_typed(&self) -> Angle {
+        Angle::radians(self.angle)
+    }
+}
+
 impl<'ctx> Solution<'ctx> {
     /// Create a new solution from a Z3 model
     ///
@@ -90,6 +116,7 @@ impl<'ctx> Solution<'ctx> {
         Self {
             model,
             point_coords: HashMap::new(),
+            point_vars: HashMap::new(),
             line_params: HashMap::new(),
             circle_params: HashMap::new(),
             parameter_vars: HashMap::new(),
@@ -99,7 +126,8 @@ impl<'ctx> Solution<'ctx> {
     /// Extract point coordinates from the Z3 model
     ///
     /// This method evaluates the point's x and y variables in the Z3 model
-    /// and converts them to floating-point coordinates.
+    /// and converts them to floating-point coordinates. The variables are
+    /// kept and can be retrieved with `point_variable`.
     ///
     /// # Arguments
     /// * `point_id` - ID of the point to extract coordinates for
@@ -148,10 +176,21 @@ impl<'ctx> Solution<'ctx> {
 
         // Cache the result
         self.point_coords.insert(point_id, (x, y));
+        self.point_vars
+            .insert(point_id, (x_var.clone(), y_var.clone()));
 
         Ok((x, y))
     }
 
+    /// Get the Z3 variables of an extracted point
+    ///
+    /// Lets callers build expressions over a point's coordinates after
+    /// solving and evaluate them with `eval_real`. Returns `None` if the
+    /// point's coordinates were not extracted.
+    pub fn point_variable(&self, point_id: PointId) -> Option<(&Real<'ctx>, &Real<'ctx>)> {
+        self.point_vars.get(&point_id).map(|(x, y)| (x, y))
+    }
+
     /// Get cached point coordinates by ID
     ///
     /// Returns the coordinates if they have been previously extracted,
@@ -168,6 +207,29 @@ impl<'ctx> Solution<'ctx> {
         })
     }
 
+    /// Get a point's polar coordinates relative to another point
+    ///
+    /// Both points must have been extracted already.
+    ///
+    /// # Arguments
+    /// * `point` - ID of the point to express in polar form
+    /// * `center` - ID of the point used as the pole
+    ///
+    /// # Returns
+    /// Radius and angle (counter-clockwise from +x, in [-π, π]) of `point` about `center`
+    pub fn polar_coordinates(&self, point: PointId, center: PointId) -> Result<(Length, Angle)> {
+        let (px, py) = self.get_point_coordinates(point)?;
+        let (cx, cy) = self.get_point_coordinates(center)?;
+
+        let dx = px - cx;
+        let dy = py - cy;
+
+        Ok((
+            Length::meters((dx * dx + dy * dy).sqrt()),
+            Angle::radians(dy.atan2(dx)),
+        ))
+    }
+
     /// Get all cached point coordinates
     ///
     /// Returns a reference to the internal HashMap containing all
@@ -183,6 +245,51 @@ impl<'ctx> Solution<'ctx> {
         &self.model
     }
 
+    /// Dump every declaration in the model with its interpretation
+    ///
+    /// Covers all variables the solver assigned, including hidden helper
+    /// variables that are never extracted. Constants are paired with their
+    /// value and functions with their interpretation, both as Z3 prints them.
+    /// Entries are sorted by name.
+    pub fn model_dump(&self) -> Vec<(String, String)> {
+        let mut dump: Vec<(String, String)> = self
+            .model
+            .iter()
+            .map(|decl| {
+                let value = if decl.arity() == 0 {
+                    self.model
+                        .eval(&decl.apply(&[]), true)
+                        .map(|value| value.to_string())
+                } else {
+                    self.model
+                        .get_func_interp(&decl)
+                        .map(|interp| interp.to_string())
+                };
+                (decl.name(), value.unwrap_or_default())
+            })
+            .collect();
+        dump.sort();
+        dump
+    }
+
+    /// Evaluate an arbitrary Z3 real expression against the model
+    ///
+    /// Useful for custom constraints that build their own expressions from
+    /// sketch variables. Unconstrained variables are completed with defaults.
+    ///
+    /// # Arguments
+    /// * `expr` - Z3 Real expression to evaluate
+    ///
+    /// # Returns
+    /// Floating-point value of the expression
+    pub fn eval_real(&self, expr: &Real<'ctx>) -> Result<f64> {
+        let value = self.model.eval(expr, true).ok_or_else(|| {
+            TextCadError::SolutionError(format!("Failed to evaluate expression {}", expr))
+        })?;
+
+        rational_to_f64_enhanced(value.into(), "expression")
+    }
+
     /// Extract parameter variable value from the Z3 model
     ///
     /// This method evaluates a named parameter variable (e.g., from parametric constraints)
@@ -309,6 +416,44 @@ impl<'ctx> Solution<'ctx> {
         })
     }
 
+    /// Get the angle of a previously extracted line
+    ///
+    /// # Arguments
+    /// * `line_id` - ID of the line to get the angle for
+    ///
+    /// # Returns
+    /// Angle of the line measured from start to end
+    pub fn line_angle(&self, line_id: LineId) -> Result<Angle> {
+        self.get_line_parameters(line_id)
+            .map(|params| params.angle_typed())
+    }
+
+    /// Intersect the infinite lines through two previously extracted lines
+    ///
+    /// # Arguments
+    /// * `l1` - ID of the first line
+    /// * `l2` - ID of the second line
+    ///
+    /// # Returns
+    /// Crossing point in meters, or `None` if the lines are parallel (or
+    /// either has zero length)
+    pub fn line_intersection(&self, l1: LineId, l2: LineId) -> Result<Option<(f64, f64)>> {
+        let a = self.get_line_parameters(l1)?;
+        let b = self.get_line_parameters(l2)?;
+
+        let (d1x, d1y) = (a.end.0 - a.start.0, a.end.1 - a.start.1);
+        let (d2x, d2y) = (b.end.0 - b.start.0, b.end.1 - b.start.1);
+        let denom = d1x * d2y - d1y * d2x;
+        if denom.abs() <= 1e-12 * a.length * b.length {
+            return Ok(None);
+        }
+
+        // Solve start_a + t·d1 = start_b + s·d2 for t
+        let (wx, wy) = (b.start.0 - a.start.0, b.start.1 - a.start.1);
+        let t = (wx * d2y - wy * d2x) / denom;
+        Ok(Some((a.start.0 + t * d1x, a.start.1 + t * d1y)))
+    }
+
     /// Extract circle parameters from the Z3 model
     ///
     /// This method calculates comprehensive circle parameters including
@@ -374,6 +519,188 @@ impl<'ctx> Solution<'ctx> {
             TextCadError::SolutionError(format!("Circle {:?} parameters not extracted", circle_id))
         })
     }
+
+    /// Take a snapshot of all extracted points, lines, and circles
+    ///
+    /// Only values already extracted into the caches are included.
+    pub fn snapshot(&self) -> GeometrySnapshot {
+        let mut points: Vec<_> = self
+            .point_coords
+            .iter()
+            .map(|(id, coords)| (*id, *coords))
+            .collect();
+        let mut lines: Vec<_> = self
+            .line_params
+            .iter()
+            .map(|(id, params)| (*id, *params))
+            .collect();
+        let mut circles: Vec<_> = self
+            .circle_params
+            .iter()
+            .map(|(id, params)| (*id, *params))
+            .collect();
+
+        points.sort_by_key(|(id, _)| *id);
+        lines.sort_by_key(|(id, _)| *id);
+        circles.sort_by_key(|(id, _)| *id);
+
+        GeometrySnapshot {
+            points,
+            lines,
+            circles,
+        }
+    }
+
+    /// Points whose coordinates differ from `other` by more than `tol`
+    ///
+    /// A point counts as changed if either coordinate moved by more than `tol`,
+    /// or if it was extracted in only one of the two solutions. The result is
+    /// sorted by PointId.
+    pub fn diff(&self, other: &Solution, tol: f64) -> Vec<PointId> {
+        let mut changed: Vec<PointId> = self
+            .point_coords
+            .iter()
+            .filter(|(id, (x, y))| match other.point_coords.get(id) {
+                Some((ox, oy)) => (x - ox).abs() > tol || (y - oy).abs() > tol,
+                None => true,
+            })
+            .map(|(id, _)| *id)
+            .chain(
+                other
+                    .point_coords
+                    .keys()
+                    .filter(|id| !self.point_coords.contains_key(id))
+                    .copied(),
+            )
+            .collect();
+
+        changed.sort();
+        changed
+    }
+
+    /// Report how well each of the sketch's constraints holds in this solution
+    ///
+    /// Pairs every constraint's `description()` with its residual, the largest
+    /// numeric violation among its assertions, in insertion order. Constraints
+    /// that cannot be applied report an infinite residual.
+    pub fn residual_report(&self, sketch: &Sketch<'ctx>) -> Vec<(String, f64)> {
+        sketch.constraint_residuals(&self.model)
+    }
+
+    /// Freeze this solution into a new sketch where every point is pinned
+    ///
+    /// Recreates the template's points, lines, circles, arcs and ellipses
+    /// (with their names) in `ctx`, adds a `FixedPositionConstraint` for each
+    /// point at its solved coordinates, and pins each circle radius and
+    /// ellipse semi-axis at its solved value with a `CircleRadiusConstraint`
+    /// or `EllipseSemiAxesConstraint`.
+    /// Entities are recreated in index order, so their IDs match the
+    /// template's as long as no entities were removed from it.
+    ///
+    /// # Arguments
+    /// * `ctx` - Z3 context for the new sketch
+    /// * `template` - The sketch this solution was extracted from
+    pub fn to_fixed_sketch<'b>(
+        &self,
+        ctx: &'b Context,
+        template: &Sketch<'ctx>,
+    ) -> Result<Sketch<'b>> {
+        let mut frozen = Sketch::new(ctx);
+
+        let mut points = HashMap::new();
+        for (id, point) in template.points() {
+            let (x, y) = self.get_point_coordinates(id)?;
+            let new_id = frozen.add_point(point.name.clone());
+            frozen.add_constraint(FixedPositionConstraint::new(
+                new_id,
+                Length::meters(x),
+                Length::meters(y),
+            ));
+            points.insert(id, new_id);
+        }
+        let point = |id: PointId| {
+            points.get(&id).copied().ok_or_else(|| {
+                TextCadError::EntityError(format!("Point {:?} not found in template", id))
+            })
+        };
+
+        for (_, line) in template.lines() {
+            let new_id = frozen.add_line(point(line.start)?, point(line.end)?, line.name.clone());
+            frozen.set_line_metadata(new_id, line.metadata.clone())?;
+        }
+
+        let mut circles = HashMap::new();
+        for (id, circle) in template.circles() {
+            let radius = self.get_circle_parameters(id)?.radius;
+            let new_id = frozen.add_circle_with_radius(
+                point(circle.center)?,
+                Length::meters(radius),
+                circle.name.clone(),
+            );
+            frozen.set_circle_metadata(new_id, circle.metadata.clone())?;
+            circles.insert(id, new_id);
+        }
+
+        for (_, arc) in template.arcs() {
+            let circle = circles.get(&arc.circle).copied().ok_or_else(|| {
+                TextCadError::EntityError(format!("Circle {:?} not found in template", arc.circle))
+            })?;
+            frozen.add_arc(circle, point(arc.start)?, point(arc.end)?, arc.name.clone());
+        }
+
+        for (_, ellipse) in template.ellipses() {
+            let semi_major = self.eval_real(&ellipse.semi_major)?;
+            let semi_minor = self.eval_real(&ellipse.semi_minor)?;
+            let new_id = frozen.add_ellipse(point(ellipse.center)?, ellipse.name.clone());
+            frozen.add_constraint(EllipseSemiAxesConstraint::new(
+                new_id,
+                Length::meters(semi_major),
+                Length::meters(semi_minor),
+            ));
+        }
+
+        Ok(frozen)
+    }
+
+    /// Check whether two solutions hold the same geometry within `tol`
+    ///
+    /// Compares all cached point coordinates, line parameters, and circle
+    /// parameters. Entities extracted in only one of the solutions make the
+    /// result false.
+    pub fn approx_eq(&self, other: &Solution, tol: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= tol;
+        let close_pair = |a: (f64, f64), b: (f64, f64)| close(a.0, b.0) && close(a.1, b.1);
+
+        let points_match = self.point_coords.len() == other.point_coords.len()
+            && self.point_coords.iter().all(|(id, &coords)| {
+                other
+                    .point_coords
+                    .get(id)
+                    .is_some_and(|&theirs| close_pair(coords, theirs))
+            });
+
+        let lines_match = self.line_params.len() == other.line_params.len()
+            && self.line_params.iter().all(|(id, a)| {
+                other.line_params.get(id).is_some_and(|b| {
+                    close_pair(a.start, b.start)
+                        && close_pair(a.end, b.end)
+                        && close(a.length, b.length)
+                        && close(a.angle, b.angle)
+                })
+            });
+
+        let circles_match = self.circle_params.len() == other.circle_params.len()
+            && self.circle_params.iter().all(|(id, a)| {
+                other.circle_params.get(id).is_some_and(|b| {
+                    close_pair(a.center, b.center)
+                        && close(a.radius, b.radius)
+                        && close(a.circumference, b.circumference)
+                        && close(a.area, b.area)
+                })
+            });
+
+        points_match && lines_match && circles_match
+    }
 }
 
 /// Convert a Z3 Real AST node to an f64 value
@@ -431,6 +758,8 @@ fn rational_to_f64_enhanced(ast: z3::ast::Dynamic, context: &str) -> Result<f64>
                 );
             }
 
+            Ok(result)
+        } else if let Some(result) = large_rational_to_f64(&real_ast) {
             Ok(result)
         } else {
             Err(TextCadError::SolutionError(format!(
@@ -446,6 +775,35 @@ fn rational_to_f64_enhanced(ast: z3::ast::Dynamic, context: &str) -> Result<f64>
     }
 }
 
+/// Convert a Z3 rational whose numerator or denominator exceeds i64
+///
+/// Exactly encoded inputs such as micrometre lengths produce such values;
+/// they are read back from the printed rational.
+pub(crate) fn large_rational_to_f64(real: &Real) -> Option<f64> {
+    numeral_str_to_f64(&real.to_string())
+}
+
+/// Evaluate a Z3 numeral printed as an s-expression, e.g. `(- (/ 1.0 4.0))`
+fn numeral_str_to_f64(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let value = match text.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        Some(inner) => {
+            let inner = inner.trim();
+            if let Some(negated) = inner.strip_prefix('-') {
+                -numeral_str_to_f64(negated)?
+            } else if let Some(fraction) = inner.strip_prefix('/') {
+                let (num, den) = fraction.trim().split_once(char::is_whitespace)?;
+                numeral_str_to_f64(num)? / numeral_str_to_f64(den)?
+            } else {
+                return None;
+            }
+        }
+        None if text.starts_with(|c: char| c.is_ascii_digit()) => text.parse().ok()?,
+        None => return None,
+    };
+    value.is_finite().then_some(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -637,6 +995,21 @@ mod tests {
         assert_eq!(params.radius, params_cached.radius);
     }
 
+    #[test]
+    fn test_enhanced_rational_conversion_beyond_i64() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+
+        // 2^-80 and -3 * 2^70 do not fit in an i64 numerator/denominator
+        let tiny = Real::from_real_str(&ctx, "1", "1208925819614629174706176").unwrap();
+        let result = rational_to_f64_enhanced(tiny.simplify().into(), "tiny").unwrap();
+        assert_eq!(result, 2f64.powi(-80));
+
+        let huge = Real::from_real_str(&ctx, "-3541774862152233910272", "1").unwrap();
+        let result = rational_to_f64_enhanced(huge.simplify().into(), "huge").unwrap();
+        assert_eq!(result, -3.0 * 2f64.powi(70));
+    }
+
     #[test]
     fn test_enhanced_rational_conversion_errors() {
         let cfg = Config::new();
@@ -742,6 +1115,188 @@ mod tests {
         assert!((params.angle - 0.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_line_angle_typed_vertical_line() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        // Add a trivial constraint to get a satisfiable model
+        let x = Real::new_const(&ctx, "dummy");
+        let zero = Real::from_real(&ctx, 0, 1);
+        solver.assert(&x._eq(&zero));
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let mut solution = Solution::new(model);
+
+        let line_id = LineId(Index::from_raw_parts(0, 0));
+        let params = solution
+            .extract_line_parameters(line_id, (0.0, 0.0), (0.0, 2.0))
+            .unwrap();
+
+        assert!((params.angle_typed().to_degrees() - 90.0).abs() < 1e-10);
+
+        let angle = solution.line_angle(line_id).unwrap();
+        assert!((angle.to_degrees() - 90.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_eval_real_custom_expression() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let x = Real::new_const(&ctx, "x");
+        let y = Real::new_const(&ctx, "y");
+        solver.assert(&x._eq(&Real::from_real(&ctx, 3, 2)));
+        solver.assert(&y._eq(&Real::from_real(&ctx, 5, 4)));
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let mut solution = Solution::new(model);
+
+        let point_id = PointId(Index::from_raw_parts(0, 0));
+        let (px, py) = solution
+            .extract_point_coordinates(point_id, &x, &y)
+            .unwrap();
+
+        let sum = Real::add(&ctx, &[&x, &y]);
+        let value = solution.eval_real(&sum).unwrap();
+
+        assert!((value - (px + py)).abs() < 1e-12);
+        assert!((value - 2.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_polar_coordinates_about_origin() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let cx = Real::new_const(&ctx, "cx");
+        let cy = Real::new_const(&ctx, "cy");
+        let px = Real::new_const(&ctx, "px");
+        let py = Real::new_const(&ctx, "py");
+        let zero = Real::from_real(&ctx, 0, 1);
+        solver.assert(&cx._eq(&zero));
+        solver.assert(&cy._eq(&zero));
+        solver.assert(&px._eq(&zero));
+        solver.assert(&py._eq(&Real::from_real(&ctx, 2, 1)));
+
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        let mut solution = Solution::new(model);
+
+        let center = PointId(Index::from_raw_parts(0, 0));
+        let point = PointId(Index::from_raw_parts(1, 0));
+        solution
+            .extract_point_coordinates(center, &cx, &cy)
+            .unwrap();
+        solution.extract_point_coordinates(point, &px, &py).unwrap();
+
+        let (radius, angle) = solution.polar_coordinates(point, center).unwrap();
+        assert!((radius.to_meters() - 2.0).abs() < 1e-10);
+        assert!((angle.to_degrees() - 90.0).abs() < 1e-10);
+
+        // Unextracted points are reported as errors
+        let missing = PointId(Index::from_raw_parts(5, 0));
+        assert!(solution.polar_coordinates(missing, center).is_err());
+    }
+
+    /// Solution with the given points fixed at integer coordinates
+    fn fixed_solution<'ctx>(ctx: &'ctx Context, coords: &[(i32, i32)]) -> Solution<'ctx> {
+        let solver = Solver::new(ctx);
+        let vars: Vec<_> = (0..coords.len())
+            .map(|i| {
+                (
+                    Real::new_const(ctx, format!("x{}", i)),
+                    Real::new_const(ctx, format!("y{}", i)),
+                )
+            })
+            .collect();
+        for ((x, y), &(vx, vy)) in vars.iter().zip(coords) {
+            solver.assert(&x._eq(&Real::from_real(ctx, vx, 1)));
+            solver.assert(&y._eq(&Real::from_real(ctx, vy, 1)));
+        }
+        assert_eq!(solver.check(), SatResult::Sat);
+
+        let mut solution = Solution::new(solver.get_model().unwrap());
+        for (i, (x, y)) in vars.iter().enumerate() {
+            solution
+                .extract_point_coordinates(PointId(Index::from_raw_parts(i, 0)), x, y)
+                .unwrap();
+        }
+        solution
+    }
+
+    #[test]
+    fn test_diff_reports_only_moved_points() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+
+        let before = fixed_solution(&ctx, &[(0, 0), (1, 1), (2, 2)]);
+        let after = fixed_solution(&ctx, &[(0, 0), (1, 3), (2, 2)]);
+
+        let moved = PointId(Index::from_raw_parts(1, 0));
+        assert_eq!(before.diff(&after, 1e-9), vec![moved]);
+        assert_eq!(after.diff(&before, 1e-9), vec![moved]);
+        assert!(before.diff(&before, 1e-9).is_empty());
+
+        // Movement within tolerance is ignored
+        assert!(before.diff(&after, 5.0).is_empty());
+    }
+
+    #[test]
+    fn test_residual_report_for_solved_rectangle() {
+        use crate::constraints::FixedPositionConstraint;
+
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let origin = sketch.add_point(Some("origin".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            origin,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_rectangle(origin, Length::meters(4.0), Length::meters(3.0));
+
+        let solution = sketch.solve_and_extract().unwrap();
+        let report = solution.residual_report(&sketch);
+
+        assert_eq!(report.len(), 6);
+        for (description, residual) in &report {
+            assert!(
+                *residual < 1e-6,
+                "{} has residual {}",
+                description,
+                residual
+            );
+        }
+    }
+
+    #[test]
+    fn test_approx_eq_tolerance_and_missing_entries() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+
+        let base = fixed_solution(&ctx, &[(0, 0), (1000, 2000)]);
+        let perturbed = fixed_solution(&ctx, &[(0, 0), (1000, 2001)]);
+        let partial = fixed_solution(&ctx, &[(0, 0)]);
+
+        assert!(base.approx_eq(&base, 0.0));
+
+        // The 1m nudge is outside a tight tolerance but within a loose one
+        assert!(!base.approx_eq(&perturbed, 0.5));
+        assert!(base.approx_eq(&perturbed, 2.0));
+
+        // A point missing on either side never compares equal
+        assert!(!base.approx_eq(&partial, 1e9));
+        assert!(!partial.approx_eq(&base, 1e9));
+    }
+
     // Property-based tests using proptest
     use proptest::prelude::*;
 
@@ -878,4 +1433,42 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_line_intersection_crossing_and_parallel() {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+        assert_eq!(solver.check(), SatResult::Sat);
+        let mut solution = Solution::new(solver.get_model().unwrap());
+
+        let horizontal = LineId(Index::from_raw_parts(0, 0));
+        let vertical = LineId(Index::from_raw_parts(1, 0));
+        let parallel = LineId(Index::from_raw_parts(2, 0));
+        solution
+            .extract_line_parameters(horizontal, (0.0, 1.0), (2.0, 1.0))
+            .unwrap();
+        solution
+            .extract_line_parameters(vertical, (5.0, -3.0), (5.0, -2.0))
+            .unwrap();
+        solution
+            .extract_line_parameters(parallel, (-1.0, 4.0), (3.0, 4.0))
+            .unwrap();
+
+        // Crossing lies beyond both segments
+        let (x, y) = solution
+            .line_intersection(horizontal, vertical)
+            .unwrap()
+            .unwrap();
+        assert!((x - 5.0).abs() < 1e-12);
+        assert!((y - 1.0).abs() < 1e-12);
+
+        assert_eq!(
+            solution.line_intersection(horizontal, parallel).unwrap(),
+            None
+        );
+
+        let missing = LineId(Index::from_raw_parts(9, 0));
+        assert!(solution.line_intersection(horizontal, missing).is_err());
+    }
 }
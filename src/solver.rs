@@ -2,8 +2,17 @@
 //!
 //! This module defines abstract interfaces for constraint solvers,
 //! allowing for future extensibility to support different solving engines.
-
-use crate::error::Result;
+//! It also provides `SolveJob` and `run_jobs` for solving sketch
+//! descriptions on worker threads.
+
+use crate::constraint::Constraint;
+use crate::entities::PointId;
+use crate::entity::{CircleId, LineId};
+use crate::error::{Result, TextCadError};
+use crate::sketch::Sketch;
+use crate::solution::GeometrySnapshot;
+use generational_arena::Index;
+use z3::{Config, Context};
 
 /// A solution extracted from a constraint solver
 ///
@@ -103,6 +112,130 @@ pub trait SolverMetadata {
     fn solver_info(&self) -> SolverInfo;
 }
 
+/// Context-free recipe for building a sketch
+///
+/// Records points, lines, circles, and constraints without touching Z3, so it
+/// is `Send` and can be moved to another thread. IDs returned while describing
+/// are the IDs the entities get when `build` replays the recipe into a fresh
+/// sketch, so constraints can reference them directly.
+#[derive(Debug, Default)]
+pub struct SketchDescription {
+    points: Vec<Option<String>>,
+    lines: Vec<(PointId, PointId, Option<String>)>,
+    circles: Vec<(PointId, Option<String>)>,
+    constraints: Vec<Box<dyn Constraint>>,
+}
+
+impl SketchDescription {
+    /// Create an empty sketch description
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Describe a new point, returning the ID it will have once built
+    pub fn add_point(&mut self, name: Option<String>) -> PointId {
+        self.points.push(name);
+        PointId::from(Index::from_raw_parts(self.points.len() - 1, 0))
+    }
+
+    /// Describe a new line, returning the ID it will have once built
+    pub fn add_line(&mut self, start: PointId, end: PointId, name: Option<String>) -> LineId {
+        self.lines.push((start, end, name));
+        LineId::from(Index::from_raw_parts(self.lines.len() - 1, 0))
+    }
+
+    /// Describe a new circle, returning the ID it will have once built
+    pub fn add_circle(&mut self, center: PointId, name: Option<String>) -> CircleId {
+        self.circles.push((center, name));
+        CircleId::from(Index::from_raw_parts(self.circles.len() - 1, 0))
+    }
+
+    /// Describe a constraint to add once built
+    pub fn add_constraint(&mut self, constraint: impl Constraint + 'static) {
+        self.constraints.push(Box::new(constraint));
+    }
+
+    /// Replay the description into a fresh sketch on the given context
+    pub fn build(self, ctx: &Context) -> Sketch<'_> {
+        let mut sketch = Sketch::new(ctx);
+        for name in self.points {
+            sketch.add_point(name);
+        }
+        for (start, end, name) in self.lines {
+            sketch.add_line(start, end, name);
+        }
+        for (center, name) in self.circles {
+            sketch.add_circle(center, name);
+        }
+        for constraint in self.constraints {
+            sketch.add_boxed_constraint(constraint);
+        }
+        sketch
+    }
+}
+
+/// Setup step run on a job's sketch before solving
+pub type SolveSetup = Box<dyn FnOnce(&mut Sketch<'_>) -> Result<()> + Send>;
+
+/// Unit of work for `run_jobs`
+///
+/// Pairs a sketch description with a setup closure that runs on the built
+/// sketch (for example to set solver parameters) before it is solved.
+pub struct SolveJob {
+    /// Sketch to build and solve
+    pub description: SketchDescription,
+    /// Setup applied to the built sketch before solving
+    pub setup: SolveSetup,
+}
+
+impl SolveJob {
+    /// Create a job with a setup closure
+    pub fn new(
+        description: SketchDescription,
+        setup: impl FnOnce(&mut Sketch<'_>) -> Result<()> + Send + 'static,
+    ) -> Self {
+        Self {
+            description,
+            setup: Box::new(setup),
+        }
+    }
+
+    /// Create a job that solves the description as-is
+    pub fn from_description(description: SketchDescription) -> Self {
+        Self::new(description, |_| Ok(()))
+    }
+
+    /// Build, set up, and solve the job on a fresh Z3 context
+    fn run(self) -> Result<GeometrySnapshot> {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = self.description.build(&ctx);
+        (self.setup)(&mut sketch)?;
+        let solution = sketch.solve_and_extract()?;
+        Ok(solution.snapshot())
+    }
+}
+
+/// Solve each job on its own worker thread
+///
+/// Every job gets a fresh Z3 context inside its thread, since contexts cannot
+/// be shared across threads. Results are returned in job order.
+pub fn run_jobs(jobs: Vec<SolveJob>) -> Vec<Result<GeometrySnapshot>> {
+    let handles: Vec<_> = jobs
+        .into_iter()
+        .map(|job| std::thread::spawn(move || job.run()))
+        .collect();
+
+    handles
+        .into_iter()
+        .map(|handle| {
+            handle.join().unwrap_or_else(|_| {
+                Err(TextCadError::SolverError("Solve job panicked".to_string()))
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,6 +317,48 @@ mod tests {
         assert_eq!(solver.constraint_count(), 0);
     }
 
+    #[test]
+    fn test_run_jobs_returns_snapshots_in_order() {
+        use crate::constraints::{FixedPositionConstraint, LineLengthConstraint};
+        use crate::units::Length;
+
+        fn assert_send<T: Send>() {}
+        assert_send::<SolveJob>();
+
+        let jobs: Vec<SolveJob> = [2.0, 5.0]
+            .into_iter()
+            .map(|length| {
+                let mut description = SketchDescription::new();
+                let a = description.add_point(Some("a".to_string()));
+                let b = description.add_point(Some("b".to_string()));
+                let line = description.add_line(a, b, None);
+                description.add_constraint(FixedPositionConstraint::new(
+                    a,
+                    Length::meters(0.0),
+                    Length::meters(0.0),
+                ));
+                description.add_constraint(FixedPositionConstraint::new(
+                    b,
+                    Length::meters(length),
+                    Length::meters(0.0),
+                ));
+                description.add_constraint(LineLengthConstraint::new(line, Length::meters(length)));
+                SolveJob::from_description(description)
+            })
+            .collect();
+
+        let results = run_jobs(jobs);
+        assert_eq!(results.len(), 2);
+
+        for (result, length) in results.into_iter().zip([2.0, 5.0]) {
+            let snapshot = result.unwrap();
+            assert_eq!(snapshot.points.len(), 2);
+            assert_eq!(snapshot.lines.len(), 1);
+            assert!((snapshot.lines[0].1.length - length).abs() < 1e-6);
+            assert!((snapshot.points[1].1.0 - length).abs() < 1e-6);
+        }
+    }
+
     #[test]
     fn test_solver_info() {
         let info = SolverInfo {
@@ -1,39 +1,72 @@
+use std::cmp::Ordering;
+use std::fmt;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// A length value stored in meters.
 /// Provides type-safe unit conversions.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+///
+/// Lengths created from integral values (or via `from_rational`) also keep
+/// an exact rational number of meters, which constraints use to avoid
+/// floating-point rounding. Comparisons only consider the f64 value.
+#[derive(Debug, Clone, Copy)]
 pub struct Length {
     meters: f64,
+    /// Exact value in meters as a reduced fraction (numerator, denominator)
+    exact: Option<(i64, i64)>,
 }
 
 impl Length {
     /// Create a length from meters
     pub fn meters(value: f64) -> Self {
-        Self { meters: value }
+        Self::with_exact(value, value, 1, 1)
     }
 
     /// Create a length from millimeters
     pub fn millimeters(value: f64) -> Self {
-        Self {
-            meters: value / 1000.0,
-        }
+        Self::with_exact(value / 1000.0, value, 1, 1000)
     }
 
     /// Create a length from centimeters
     pub fn centimeters(value: f64) -> Self {
-        Self {
-            meters: value / 100.0,
-        }
+        Self::with_exact(value / 100.0, value, 1, 100)
     }
 
     /// Create a length from inches
     pub fn inches(value: f64) -> Self {
+        Self::with_exact(value * 0.0254, value, 254, 10_000)
+    }
+
+    /// Create a length from an exact rational number of meters
+    ///
+    /// # Panics
+    /// Panics if `den` is zero.
+    pub fn from_rational(num: i64, den: i64) -> Self {
+        assert!(den != 0, "Length denominator must be non-zero");
         Self {
-            meters: value * 0.0254,
+            meters: num as f64 / den as f64,
+            exact: Some(reduce_fraction(num, den)),
         }
     }
 
+    /// Build a length, recording `value * num / den` meters exactly when `value` is integral
+    fn with_exact(meters: f64, value: f64, num: i64, den: i64) -> Self {
+        // Integers up to 2^53 are exactly representable as f64
+        const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+        let exact = if value.fract() == 0.0 && value.abs() <= MAX_EXACT {
+            (value as i64)
+                .checked_mul(num)
+                .map(|scaled| reduce_fraction(scaled, den))
+        } else {
+            None
+        };
+        Self { meters, exact }
+    }
+
+    /// Get the exact value in meters as (numerator, denominator), if known
+    pub fn exact_rational(self) -> Option<(i64, i64)> {
+        self.exact
+    }
+
     /// Get the value in meters
     pub fn to_meters(self) -> f64 {
         self.meters
@@ -58,15 +91,61 @@ impl Length {
     pub fn is_zero(self, epsilon: f64) -> bool {
         self.meters.abs() < epsilon
     }
+
+    /// Check if the length is strictly negative
+    pub fn is_negative(self) -> bool {
+        self.meters < 0.0
+    }
+
+    /// Restrict the length to the range [min, max]
+    pub fn clamp(self, min: Length, max: Length) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Length without an exact rational, used for results of f64 arithmetic
+    fn inexact(meters: f64) -> Self {
+        Self {
+            meters,
+            exact: None,
+        }
+    }
+}
+
+/// Reduce a fraction to lowest terms with a positive denominator
+fn reduce_fraction(num: i64, den: i64) -> (i64, i64) {
+    let mut a = num.unsigned_abs();
+    let mut b = den.unsigned_abs();
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    let gcd = a.max(1) as i64;
+    let sign = if den < 0 { -1 } else { 1 };
+    (sign * num / gcd, sign * den / gcd)
+}
+
+impl PartialEq for Length {
+    fn eq(&self, other: &Self) -> bool {
+        self.meters == other.meters
+    }
+}
+
+impl PartialOrd for Length {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.meters.partial_cmp(&other.meters)
+    }
 }
 
 impl Add for Length {
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
-        Self {
-            meters: self.meters + other.meters,
-        }
+        Self::inexact(self.meters + other.meters)
     }
 }
 
@@ -74,9 +153,7 @@ impl Sub for Length {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self::Output {
-        Self {
-            meters: self.meters - other.meters,
-        }
+        Self::inexact(self.meters - other.meters)
     }
 }
 
@@ -84,9 +161,7 @@ impl Mul<f64> for Length {
     type Output = Self;
 
     fn mul(self, scalar: f64) -> Self::Output {
-        Self {
-            meters: self.meters * scalar,
-        }
+        Self::inexact(self.meters * scalar)
     }
 }
 
@@ -94,9 +169,7 @@ impl Mul<Length> for f64 {
     type Output = Length;
 
     fn mul(self, length: Length) -> Self::Output {
-        Length {
-            meters: self * length.meters,
-        }
+        Length::inexact(self * length.meters)
     }
 }
 
@@ -114,9 +187,7 @@ impl Div<f64> for Length {
     type Output = Self;
 
     fn div(self, scalar: f64) -> Self::Output {
-        Self {
-            meters: self.meters / scalar,
-        }
+        Self::inexact(self.meters / scalar)
     }
 }
 
@@ -134,10 +205,19 @@ impl Neg for Length {
     fn neg(self) -> Self::Output {
         Self {
             meters: -self.meters,
+            exact: self.exact.map(|(num, den)| (-num, den)),
         }
     }
 }
 
+/// Formats in meters, with 3 decimal places unless a precision is given
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        write!(f, "{:.precision$} m", self.meters)
+    }
+}
+
 /// An area value stored in square meters.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Area {
@@ -152,6 +232,16 @@ impl Area {
         }
     }
 
+    /// Create an area from square millimeters
+    pub fn square_millimeters(value: f64) -> Self {
+        Self::square_meters(value / 1_000_000.0)
+    }
+
+    /// Create an area from square centimeters
+    pub fn square_centimeters(value: f64) -> Self {
+        Self::square_meters(value / 10_000.0)
+    }
+
     /// Get the value in square meters
     pub fn to_square_meters(self) -> f64 {
         self.square_meters
@@ -161,6 +251,16 @@ impl Area {
     pub fn to_square_millimeters(self) -> f64 {
         self.square_meters * 1_000_000.0
     }
+
+    /// Get the value in square centimeters
+    pub fn to_square_centimeters(self) -> f64 {
+        self.square_meters * 10_000.0
+    }
+
+    /// Check if the area is strictly negative
+    pub fn is_negative(self) -> bool {
+        self.square_meters < 0.0
+    }
 }
 
 impl Add for Area {
@@ -217,9 +317,7 @@ impl Div<Length> for Area {
     type Output = Length;
 
     fn div(self, length: Length) -> Self::Output {
-        Length {
-            meters: self.square_meters / length.meters,
-        }
+        Length::inexact(self.square_meters / length.meters)
     }
 }
 
@@ -231,6 +329,14 @@ impl Div<Area> for Area {
     }
 }
 
+/// Formats in square meters, with 3 decimal places unless a precision is given
+impl fmt::Display for Area {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        write!(f, "{:.precision$} m²", self.square_meters)
+    }
+}
+
 /// An angle value stored in radians.
 /// Provides type-safe unit conversions.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -370,9 +476,105 @@ impl Neg for Angle {
     }
 }
 
+/// A dimensionless value such as a count, ratio, or scale factor.
+/// Wraps an f64 so unitless parameters are explicit at call sites.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Scalar {
+    value: f64,
+}
+
+impl Scalar {
+    /// Create a scalar from a raw value
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Get the raw value
+    pub fn value(self) -> f64 {
+        self.value
+    }
+}
+
+impl From<f64> for Scalar {
+    fn from(value: f64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<Scalar> for f64 {
+    fn from(scalar: Scalar) -> Self {
+        scalar.value
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value + other.value,
+        }
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value - other.value,
+        }
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value * other.value,
+        }
+    }
+}
+
+impl Div for Scalar {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        Self {
+            value: self.value / other.value,
+        }
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { value: -self.value }
+    }
+}
+
+impl Mul<Length> for Scalar {
+    type Output = Length;
+
+    fn mul(self, length: Length) -> Self::Output {
+        self.value * length
+    }
+}
+
+impl Mul<Scalar> for Length {
+    type Output = Length;
+
+    fn mul(self, scalar: Scalar) -> Self::Output {
+        self * scalar.value
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_length_conversions() {
@@ -397,6 +599,44 @@ mod tests {
         assert_eq!(b / a, 1.5);
     }
 
+    #[test]
+    fn test_length_is_negative() {
+        assert!(Length::meters(-5.0).is_negative());
+        assert!(!Length::meters(0.0).is_negative());
+        assert!(!Length::millimeters(3.0).is_negative());
+    }
+
+    #[test]
+    fn test_area_is_negative() {
+        assert!(Area::square_meters(-0.5).is_negative());
+        assert!(!Area::square_meters(0.0).is_negative());
+        assert!(!Area::square_millimeters(2.0).is_negative());
+    }
+
+    #[test]
+    fn test_length_clamp() {
+        let min = Length::meters(0.0);
+        let max = Length::meters(10.0);
+
+        assert_eq!(Length::meters(-2.0).clamp(min, max), min);
+        assert_eq!(Length::meters(12.0).clamp(min, max), max);
+        assert_eq!(Length::meters(4.0).clamp(min, max).to_meters(), 4.0);
+    }
+
+    #[test]
+    fn test_length_exact_rational() {
+        assert_eq!(Length::meters(2000.0).exact_rational(), Some((2000, 1)));
+        assert_eq!(Length::millimeters(250.0).exact_rational(), Some((1, 4)));
+        assert_eq!(Length::inches(1.0).exact_rational(), Some((127, 5000)));
+        assert_eq!(Length::from_rational(6, -4).exact_rational(), Some((-3, 2)));
+        assert_eq!(Length::meters(0.1).exact_rational(), None);
+        assert_eq!((-Length::meters(3.0)).exact_rational(), Some((-3, 1)));
+        assert_eq!((Length::meters(1.0) * 2.0).exact_rational(), None);
+
+        // Equality ignores the exact representation
+        assert_eq!(Length::millimeters(1000.0), Length::meters(1.0));
+    }
+
     #[test]
     fn test_length_multiplication_creates_area() {
         let a = Length::meters(3.0);
@@ -413,6 +653,36 @@ mod tests {
         assert_eq!(height.to_meters(), 4.0);
     }
 
+    #[test]
+    fn test_area_conversions() {
+        let area = Area::square_centimeters(250.0);
+        assert!((area.to_square_meters() - 0.025).abs() < 1e-12);
+        assert!((area.to_square_millimeters() - 25_000.0).abs() < 1e-9);
+
+        let area_mm = Area::square_millimeters(1_000_000.0);
+        assert_eq!(area_mm.to_square_meters(), 1.0);
+        assert_eq!(area_mm.to_square_centimeters(), 10_000.0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_area_square_centimeters_round_trip(cm2 in -1.0e6f64..1.0e6f64) {
+            let square_meters = Area::square_centimeters(cm2).to_square_meters();
+            let back = Area::square_meters(square_meters).to_square_centimeters();
+            prop_assert!((back - cm2).abs() <= 1e-9 * cm2.abs().max(1.0),
+                "Expected {} cm², got {} cm²", cm2, back);
+        }
+    }
+
+    #[test]
+    fn test_length_and_area_display() {
+        assert_eq!(Length::meters(1.5).to_string(), "1.500 m");
+        assert_eq!(Length::millimeters(-250.0).to_string(), "-0.250 m");
+        assert_eq!(format!("{:.1}", Length::meters(2.0)), "2.0 m");
+        assert_eq!(Area::square_meters(2.25).to_string(), "2.250 m²");
+        assert_eq!(format!("{:.0}", Area::square_meters(12.0)), "12 m²");
+    }
+
     #[test]
     fn test_angle_conversions() {
         let angle = Angle::degrees(90.0);
@@ -454,4 +724,19 @@ mod tests {
         assert!((angle.sin() - std::f64::consts::SQRT_2 / 2.0).abs() < 1e-10);
         assert!((angle.cos() - std::f64::consts::SQRT_2 / 2.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_scalar_arithmetic() {
+        let a = Scalar::from(1.5);
+        let b = Scalar::new(0.5);
+
+        assert_eq!((a + b).value(), 2.0);
+        assert_eq!((a - b).value(), 1.0);
+        assert_eq!((a * b).value(), 0.75);
+        assert_eq!((a / b).value(), 3.0);
+        assert_eq!((-a).value(), -1.5);
+        assert_eq!(f64::from(a), 1.5);
+        assert_eq!((a * Length::meters(2.0)).to_meters(), 3.0);
+        assert_eq!((Length::meters(2.0) * b).to_meters(), 1.0);
+    }
 }
@@ -0,0 +1,133 @@
+//! Integration tests for arc constraints
+//!
+//! Tests joining lines to arcs and fixing arc sweeps and tangents through the
+//! sketch solver, checking the solved geometry.
+
+use textcad::constraints::{
+    ArcSweepConstraint, ArcTangentConstraint, FixedPositionConstraint, TangentJoinConstraint,
+    VerticalDistanceConstraint,
+};
+use textcad::sketch::Sketch;
+use textcad::units::{Angle, Length};
+use z3::ast::{Ast, Real};
+use z3::{Config, Context};
+
+#[test]
+fn test_tangent_join_line_meets_arc_tangentially() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Arc on a circle centered at the origin, starting at (1, 0)
+    let center = sketch.add_point(Some("center".to_string()));
+    let circle = sketch.add_circle(center, Some("circle".to_string()));
+    let join = sketch.add_point(Some("join".to_string()));
+    let arc_end = sketch.add_point(Some("arc_end".to_string()));
+    let arc = sketch.add_arc(circle, join, arc_end, Some("arc".to_string()));
+
+    // Line ending at the join, rising 2m to it with free x
+    let line_start = sketch.add_point(Some("line_start".to_string()));
+    let line = sketch.add_line(line_start, join, Some("line".to_string()));
+
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        join,
+        Length::meters(1.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(VerticalDistanceConstraint::new(
+        line_start,
+        join,
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(TangentJoinConstraint::new(line, arc, join));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let (cx, cy) = solution.get_point_coordinates(center).unwrap();
+    let (jx, jy) = solution.get_point_coordinates(join).unwrap();
+    let (sx, sy) = solution.get_point_coordinates(line_start).unwrap();
+
+    // The join lies on the circle
+    let radius = solution.get_circle_parameters(circle).unwrap().radius;
+    assert!((((jx - cx).powi(2) + (jy - cy).powi(2)).sqrt() - radius.abs()).abs() < 1e-6);
+
+    // Line direction is perpendicular to the radius at the join
+    let dot = (jx - sx) * (jx - cx) + (jy - sy) * (jy - cy);
+    assert!(dot.abs() < 1e-6);
+    assert!((sx - 1.0).abs() < 1e-6);
+    assert!((sy + 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_arc_sweep_quarter_turn() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(Some("center".to_string()));
+    let circle = sketch.add_circle(center, Some("circle".to_string()));
+    let start = sketch.add_point(Some("start".to_string()));
+    let end = sketch.add_point(Some("end".to_string()));
+    let arc = sketch.add_arc(circle, start, end, Some("quarter".to_string()));
+
+    sketch.add_constraint(ArcSweepConstraint::new(arc, Angle::degrees(90.0)));
+
+    // Start the arc on the positive x-axis
+    let start_angle = sketch.get_arc(arc).unwrap().start_angle.clone();
+    let end_angle = sketch.get_arc(arc).unwrap().end_angle.clone();
+    sketch
+        .solver_mut()
+        .assert(&start_angle._eq(&Real::from_real(&ctx, 0, 1)));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    assert!(solution.eval_real(&start_angle).unwrap().abs() < 1e-6);
+    assert!((solution.eval_real(&end_angle).unwrap() - std::f64::consts::FRAC_PI_2).abs() < 1e-5);
+}
+
+#[test]
+fn test_arc_tangent_horizontal_at_start() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    // Arc about the origin whose start sits 2m from the center in y
+    let build = |start_dy: f64| {
+        let mut sketch = Sketch::new(&ctx);
+        let center = sketch.add_point(Some("center".to_string()));
+        let circle = sketch.add_circle(center, Some("circle".to_string()));
+        let start = sketch.add_point(Some("start".to_string()));
+        let end = sketch.add_point(Some("end".to_string()));
+        let arc = sketch.add_arc(circle, start, end, Some("arc".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            center,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(VerticalDistanceConstraint::new(
+            center,
+            start,
+            Length::meters(start_dy),
+        ));
+        sketch.add_constraint(ArcTangentConstraint::new(arc, true, Angle::degrees(0.0)));
+        (sketch, center, start)
+    };
+
+    // Travelling counter-clockwise in +x, the start must be directly below
+    let (mut sketch, center, start) = build(-2.0);
+    let solution = sketch.solve_and_extract().unwrap();
+    let (cx, cy) = solution.get_point_coordinates(center).unwrap();
+    let (sx, sy) = solution.get_point_coordinates(start).unwrap();
+
+    // Radius at the start is vertical, so the tangent there is horizontal
+    assert!((sx - cx).abs() < 1e-6);
+    assert!((sy - cy + 2.0).abs() < 1e-6);
+
+    // Above the center, a counter-clockwise tangent would point in -x
+    let (mut sketch, _, _) = build(2.0);
+    assert!(!sketch.is_satisfiable().unwrap());
+}
@@ -4,9 +4,18 @@
 //! and basic sketch integration for Circle entities.
 
 use generational_arena::Index;
+use textcad::constraints::{
+    AllEqualRadiusConstraint, BoltCircleConstraint, ChordOnLineConstraint,
+    CircleNonOverlapConstraint, FixedPositionConstraint, HorizontalDistanceConstraint,
+    LineLengthFromCircumferenceConstraint, PointAtCircleIntersectionConstraint,
+    RadiusEqualsLineLengthConstraint, VerticalDistanceConstraint,
+};
 use textcad::entities::{Circle, PointId};
 use textcad::entity::CircleId;
+use textcad::error::TextCadError;
 use textcad::sketch::Sketch;
+use textcad::units::Length;
+use z3::ast::{Ast, Real};
 use z3::{Config, Context};
 
 #[test]
@@ -228,3 +237,362 @@ fn test_circle_debug_representation_quality() {
 // - Circle-circle tangency
 // - Circle-line tangency
 // - Solution extraction for circles
+
+#[test]
+fn test_circle_radius_equals_half_line_length() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Fixed 4m horizontal line
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let line = sketch.add_line(p1, p2, Some("line".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(4.0),
+        Length::meters(0.0),
+    ));
+
+    let center = sketch.add_point(Some("center".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(2.0),
+        Length::meters(2.0),
+    ));
+    let circle = sketch.add_circle(center, Some("fillet".to_string()));
+
+    sketch.add_constraint(RadiusEqualsLineLengthConstraint::new(circle, line, 0.5));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let params = solution.get_circle_parameters(circle).unwrap();
+    assert!((params.radius - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_all_equal_radius_hole_pattern() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // A fixed 1m reference line drives the first radius to 0.5
+    let a = sketch.add_point(Some("a".to_string()));
+    let b = sketch.add_point(Some("b".to_string()));
+    let reference = sketch.add_line(a, b, Some("reference".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        b,
+        Length::meters(1.0),
+        Length::meters(0.0),
+    ));
+
+    let circles: Vec<_> = (0..3)
+        .map(|i| {
+            let center = sketch.add_point(Some(format!("c{}", i)));
+            sketch.add_constraint(FixedPositionConstraint::new(
+                center,
+                Length::meters(2.0 * i as f64),
+                Length::meters(3.0),
+            ));
+            sketch.add_circle(center, Some(format!("hole{}", i)))
+        })
+        .collect();
+
+    sketch.add_constraint(RadiusEqualsLineLengthConstraint::new(
+        circles[0], reference, 0.5,
+    ));
+    sketch.add_constraint(AllEqualRadiusConstraint::new(circles.clone()));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    for circle in circles {
+        let params = solution.get_circle_parameters(circle).unwrap();
+        assert!((params.radius - 0.5).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_line_length_from_full_circumference() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Unit circle at the origin, radius driven by a fixed 2m reference line
+    let a = sketch.add_point(Some("a".to_string()));
+    let b = sketch.add_point(Some("b".to_string()));
+    let reference = sketch.add_line(a, b, Some("reference".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a,
+        Length::meters(0.0),
+        Length::meters(-2.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        b,
+        Length::meters(2.0),
+        Length::meters(-2.0),
+    ));
+    let center = sketch.add_point(Some("center".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    let circle = sketch.add_circle(center, Some("pulley".to_string()));
+    sketch.add_constraint(RadiusEqualsLineLengthConstraint::new(
+        circle, reference, 0.5,
+    ));
+
+    // Horizontal belt line starting at (0, 2)
+    let start = sketch.add_point(Some("start".to_string()));
+    let end = sketch.add_point(Some("end".to_string()));
+    let belt = sketch.add_line(start, end, Some("belt".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        start,
+        Length::meters(0.0),
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(VerticalDistanceConstraint::new(
+        start,
+        end,
+        Length::meters(0.0),
+    ));
+
+    sketch.add_constraint(LineLengthFromCircumferenceConstraint::new(
+        belt, circle, 1.0,
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let params = solution.get_line_parameters(belt).unwrap();
+    assert!((params.length - std::f64::consts::TAU).abs() < 1e-5);
+}
+
+#[test]
+fn test_bolt_circle_four_holes() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(Some("center".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+
+    let circles: Vec<CircleId> = (0..4)
+        .map(|i| {
+            let hole_center = sketch.add_point(Some(format!("hole_center_{}", i)));
+            sketch.add_circle(hole_center, Some(format!("hole_{}", i)))
+        })
+        .collect();
+    sketch.add_constraint(BoltCircleConstraint::new(
+        circles.clone(),
+        center,
+        Length::meters(1.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let expected = [(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, -1.0)];
+    for (circle, (ex, ey)) in circles.iter().zip(expected) {
+        let params = solution.get_circle_parameters(*circle).unwrap();
+        assert!((params.center.0 - ex).abs() < 1e-5);
+        assert!((params.center.1 - ey).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_point_at_circle_intersection() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Two radius-5 circles 6m apart meet at (3, 4) and (3, -4)
+    let centers = [(0.0, 0.0), (6.0, 0.0)];
+    let circles: Vec<CircleId> = centers
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, y))| {
+            let center = sketch.add_point(Some(format!("center{}", i)));
+            sketch.add_constraint(FixedPositionConstraint::new(
+                center,
+                Length::meters(x),
+                Length::meters(y),
+            ));
+            sketch.add_circle(center, Some(format!("circle{}", i)))
+        })
+        .collect();
+    for &circle in &circles {
+        let radius = sketch.get_circle(circle).unwrap().radius.clone();
+        sketch
+            .solver_mut()
+            .assert(&radius._eq(&Real::from_real(&ctx, 5, 1)));
+    }
+
+    let point = sketch.add_point(Some("p".to_string()));
+    sketch.add_constraint(PointAtCircleIntersectionConstraint::new(
+        point, circles[0], circles[1],
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.get_point_coordinates(point).unwrap();
+
+    for (cx, cy) in centers {
+        let distance = ((x - cx).powi(2) + (y - cy).powi(2)).sqrt();
+        assert!((distance - 5.0).abs() < 1e-6);
+    }
+    assert!((x - 3.0).abs() < 1e-6);
+    assert!((y.abs() - 4.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_chord_on_line_sets_center_distance() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Fixed line along the x-axis from (-5, 0) to (5, 0)
+    let start = sketch.add_point(Some("start".to_string()));
+    let end = sketch.add_point(Some("end".to_string()));
+    let line = sketch.add_line(start, end, Some("slot".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        start,
+        Length::meters(-5.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        end,
+        Length::meters(5.0),
+        Length::meters(0.0),
+    ));
+
+    // Radius-5 circle whose center is free to move along x = 0
+    let center = sketch.add_point(Some("center".to_string()));
+    let circle = sketch.add_circle(center, Some("cutter".to_string()));
+    sketch.add_constraint(HorizontalDistanceConstraint::new(
+        start,
+        center,
+        Length::meters(5.0),
+    ));
+    let radius = sketch.get_circle(circle).unwrap().radius.clone();
+    sketch
+        .solver_mut()
+        .assert(&radius._eq(&Real::from_real(&ctx, 5, 1)));
+
+    sketch.add_constraint(ChordOnLineConstraint::new(
+        circle,
+        line,
+        Length::meters(8.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let params = solution.get_circle_parameters(circle).unwrap();
+
+    // Intersecting the circle with y = 0 gives a chord of 2·sqrt(r² - cy²)
+    let d = params.center.1.abs();
+    assert!((d - 3.0).abs() < 1e-6);
+    let chord = 2.0 * (params.radius.powi(2) - d.powi(2)).sqrt();
+    assert!((chord - 8.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_chord_on_line_rejects_negative_chord() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let start = sketch.add_point(None);
+    let end = sketch.add_point(None);
+    let line = sketch.add_line(start, end, None);
+    let center = sketch.add_point(None);
+    let circle = sketch.add_circle(center, None);
+    sketch.add_constraint(ChordOnLineConstraint::new(
+        circle,
+        line,
+        Length::meters(-1.0),
+    ));
+
+    assert!(matches!(
+        sketch.solve_and_extract(),
+        Err(TextCadError::InvalidConstraint(_))
+    ));
+}
+
+#[test]
+fn test_circle_non_overlap() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    // Two radius-1 circles, the first centered at the origin and the second
+    // on the x-axis
+    let build = || {
+        let mut sketch = Sketch::new(&ctx);
+        let fixed_center = sketch.add_point(Some("fixed_center".to_string()));
+        let free_center = sketch.add_point(Some("free_center".to_string()));
+        let c1 = sketch.add_circle(fixed_center, Some("c1".to_string()));
+        let c2 = sketch.add_circle(free_center, Some("c2".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            fixed_center,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(VerticalDistanceConstraint::new(
+            fixed_center,
+            free_center,
+            Length::meters(0.0),
+        ));
+        for circle in [c1, c2] {
+            let radius = sketch.get_circle(circle).unwrap().radius.clone();
+            sketch
+                .solver_mut()
+                .assert(&radius._eq(&Real::from_real(&ctx, 1, 1)));
+        }
+        sketch.add_constraint(CircleNonOverlapConstraint::new(c1, c2));
+        (sketch, free_center)
+    };
+
+    let (mut sketch, free_center) = build();
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.get_point_coordinates(free_center).unwrap();
+    assert!(x.hypot(y) >= 2.0 - 1e-6);
+
+    // Pinning the second center 1m away forces an overlap
+    let (mut sketch, free_center) = build();
+    sketch.add_constraint(FixedPositionConstraint::new(
+        free_center,
+        Length::meters(1.0),
+        Length::meters(0.0),
+    ));
+    assert!(!sketch.is_satisfiable().unwrap());
+}
+
+#[test]
+fn test_add_circle_with_radius() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(Some("center".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(1.0),
+        Length::meters(-1.0),
+    ));
+    let circle =
+        sketch.add_circle_with_radius(center, Length::millimeters(250.0), Some("c".to_string()));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let params = solution.get_circle_parameters(circle).unwrap();
+
+    assert!((params.radius - 0.25).abs() < 1e-9);
+    assert!((params.center.0 - 1.0).abs() < 1e-9);
+    assert!((params.center.1 + 1.0).abs() < 1e-9);
+}
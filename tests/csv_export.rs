@@ -0,0 +1,56 @@
+//! Integration tests for CSV export
+//!
+//! Tests exporting solved sketches to CSV and reading the rows back.
+
+use textcad::constraints::FixedPositionConstraint;
+use textcad::export::{CSVExporter, Exporter};
+use textcad::sketch::Sketch;
+use textcad::units::Length;
+use z3::{Config, Context};
+
+#[test]
+fn test_csv_export_round_trip() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(1.0),
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(4.0),
+        Length::meters(6.0),
+    ));
+    let line = sketch.add_line(p1, p2, None);
+    sketch.add_circle(p1, None);
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let csv = CSVExporter::new().export(&sketch, &solution).unwrap();
+
+    let mut rows = csv.lines().map(|row| row.split(',').collect::<Vec<_>>());
+    let header = rows.next().unwrap();
+    let column = |name: &str| header.iter().position(|&c| c == name).unwrap();
+    let rows: Vec<_> = rows.collect();
+    assert_eq!(rows.len(), 4);
+    assert!(rows.iter().all(|row| row.len() == header.len()));
+
+    let find = |kind: &str, id: usize| {
+        rows.iter()
+            .find(|row| row[0] == kind && row[1] == id.to_string())
+            .unwrap()
+    };
+    let number = |row: &[&str], name: &str| row[column(name)].parse::<f64>().unwrap();
+
+    let point_row = find("point", p1.0.into_raw_parts().0);
+    assert!((number(point_row, "x") - 1.0).abs() < 1e-6);
+    assert!((number(point_row, "y") - 2.0).abs() < 1e-6);
+
+    let line_row = find("line", line.0.into_raw_parts().0);
+    assert!((number(line_row, "length") - 5.0).abs() < 1e-6);
+    assert!(rows.iter().any(|row| row[0] == "circle"));
+}
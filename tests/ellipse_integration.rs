@@ -0,0 +1,99 @@
+//! Integration tests for ellipse constraints
+//!
+//! Tests fixing an ellipse's aspect ratio or semi-axes through the sketch
+//! solver and reading back the solved semi-axes.
+
+use textcad::constraints::{
+    EllipseAspectRatioConstraint, EllipseSemiAxesConstraint, FixedPositionConstraint,
+};
+use textcad::error::TextCadError;
+use textcad::sketch::Sketch;
+use textcad::units::Length;
+use z3::ast::{Ast, Real};
+use z3::{Config, Context};
+
+#[test]
+fn test_ellipse_aspect_ratio_scales_semi_major() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(Some("center".to_string()));
+    let ellipse = sketch.add_ellipse(center, Some("oval".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(EllipseAspectRatioConstraint::new(ellipse, 2.0));
+
+    // Fix the semi-minor axis at 1
+    let semi_minor = sketch.get_ellipse(ellipse).unwrap().semi_minor.clone();
+    let semi_major = sketch.get_ellipse(ellipse).unwrap().semi_major.clone();
+    sketch
+        .solver_mut()
+        .assert(&semi_minor._eq(&Real::from_real(&ctx, 1, 1)));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    assert!((solution.eval_real(&semi_minor).unwrap() - 1.0).abs() < 1e-6);
+    assert!((solution.eval_real(&semi_major).unwrap() - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_ellipse_aspect_ratio_rejects_ratio_below_one() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(None);
+    let ellipse = sketch.add_ellipse(center, None);
+    sketch.add_constraint(EllipseAspectRatioConstraint::new(ellipse, 0.5));
+
+    assert!(matches!(
+        sketch.solve_and_extract(),
+        Err(TextCadError::InvalidConstraint(_))
+    ));
+}
+
+#[test]
+fn test_ellipse_semi_axes_fixes_both_axes() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(None);
+    let ellipse = sketch.add_ellipse(center, None);
+    sketch.fix_points(&[(center, Length::meters(1.0), Length::meters(1.0))]);
+    sketch.add_constraint(EllipseSemiAxesConstraint::new(
+        ellipse,
+        Length::meters(3.0),
+        Length::millimeters(1500.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let shape = sketch.get_ellipse(ellipse).unwrap();
+
+    assert!((solution.eval_real(&shape.semi_major).unwrap() - 3.0).abs() < 1e-6);
+    assert!((solution.eval_real(&shape.semi_minor).unwrap() - 1.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_ellipse_semi_axes_rejects_negative_length() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(None);
+    let ellipse = sketch.add_ellipse(center, None);
+    sketch.add_constraint(EllipseSemiAxesConstraint::new(
+        ellipse,
+        Length::meters(1.0),
+        Length::meters(-0.5),
+    ));
+
+    assert!(matches!(
+        sketch.solve_and_extract(),
+        Err(TextCadError::InvalidConstraint(_))
+    ));
+}
@@ -1,15 +1,20 @@
 //! Integration tests for Phase 8 line constraints
 //!
 //! Tests complete workflows including sketch creation, constraint application,
-//! solving, and solution extraction for parallel and perpendicular line constraints.
+//! solving, and solution extraction for parallel and perpendicular line constraints,
+//! inclinations, and angle ranges.
 
 use textcad::constraints::{
-    FixedPositionConstraint, LineLengthConstraint, ParallelLinesConstraint,
-    PerpendicularLinesConstraint,
+    AngleRangeConstraint, CodirectionalConstraint, CornerAngleConstraint,
+    EqualInclinationConstraint, FixedPositionConstraint, InclinationConstraint,
+    LineLengthConstraint, LinearLengthRelationConstraint, MaxLengthConstraint,
+    OffsetLineConstraint, OffsetSide, ParallelLinesConstraint, PerpendicularLinesConstraint,
+    PointSideOfLineConstraint, Side, ThroughMidpointConstraint, VerticalDistanceConstraint,
 };
 use textcad::error::TextCadError;
 use textcad::sketch::Sketch;
-use textcad::units::Length;
+use textcad::units::{Angle, Length, Scalar};
+use z3::ast::Real;
 use z3::{Config, Context};
 
 #[test]
@@ -623,3 +628,566 @@ fn test_mixed_constraint_types_complex() {
         aux_length
     );
 }
+
+#[test]
+fn test_linear_length_relation_integration() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Source line fixed from (0,0) to (2,0), i.e. length 2
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let source = sketch.add_line(p1, p2, Some("source".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(2.0),
+        Length::meters(0.0),
+    ));
+
+    // Target line starts at (0,1) and runs parallel to the source
+    let p3 = sketch.add_point(Some("p3".to_string()));
+    let p4 = sketch.add_point(Some("p4".to_string()));
+    let target = sketch.add_line(p3, p4, Some("target".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p3,
+        Length::meters(0.0),
+        Length::meters(1.0),
+    ));
+    sketch.add_constraint(ParallelLinesConstraint::new(source, target));
+
+    // len(target) = 2 * len(source) + 1 = 5
+    sketch.add_constraint(LinearLengthRelationConstraint::new(
+        target,
+        source,
+        2.0,
+        Length::meters(1.0),
+    ));
+
+    let solution = sketch
+        .solve_and_extract()
+        .expect("Should solve successfully");
+
+    let source_params = solution.get_line_parameters(source).unwrap();
+    let target_params = solution.get_line_parameters(target).unwrap();
+    assert!((source_params.length - 2.0).abs() < 1e-6);
+    assert!(
+        (target_params.length - 5.0).abs() < 1e-6,
+        "Target length should be 5, got {}",
+        target_params.length
+    );
+}
+
+/// Solve a fixed 2m source line and a parallel target scaled by `scale`
+fn solved_scaled_target_length(scale: impl Into<Scalar>) -> f64 {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let p3 = sketch.add_point(Some("p3".to_string()));
+    let p4 = sketch.add_point(Some("p4".to_string()));
+    let source = sketch.add_line(p1, p2, Some("source".to_string()));
+    let target = sketch.add_line(p3, p4, Some("target".to_string()));
+
+    for (p, x, y) in [(p1, 0.0, 0.0), (p2, 2.0, 0.0), (p3, 0.0, 1.0)] {
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(x),
+            Length::meters(y),
+        ));
+    }
+    sketch.add_constraint(ParallelLinesConstraint::new(source, target));
+    sketch.add_constraint(LinearLengthRelationConstraint::new(
+        target,
+        source,
+        scale,
+        Length::meters(0.0),
+    ));
+
+    let solution = sketch
+        .solve_and_extract()
+        .expect("Should solve successfully");
+    solution.get_line_parameters(target).unwrap().length
+}
+
+#[test]
+fn test_linear_length_relation_scalar_matches_f64() {
+    let with_f64 = solved_scaled_target_length(1.5);
+    let with_scalar = solved_scaled_target_length(Scalar::new(1.5));
+
+    assert!((with_f64 - 3.0).abs() < 1e-6);
+    assert!((with_scalar - with_f64).abs() < 1e-10);
+}
+
+#[test]
+fn test_linear_length_relation_large_scale_does_not_saturate() {
+    // 5000 * 1e6 overflows an i32 fixed-point encoding
+    let length = solved_scaled_target_length(5000.0);
+    assert!((length - 10_000.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_point_side_of_line_integration() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Horizontal line from (0,1) to (4,1), pointing in +x
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let p = sketch.add_point(Some("p".to_string()));
+    let line = sketch.add_line(p1, p2, Some("line".to_string()));
+
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(1.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(4.0),
+        Length::meters(1.0),
+    ));
+    sketch.add_constraint(PointSideOfLineConstraint::new(line, p, Side::Left));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (_, y) = solution.get_point_coordinates(p).unwrap();
+    assert!(
+        y >= 1.0 - 1e-9,
+        "Point should be above the line, got y = {}",
+        y
+    );
+
+    // Pinning the point below the line conflicts with the left side
+    let mut sketch = Sketch::new(&ctx);
+    let p1 = sketch.add_point(Some("q1".to_string()));
+    let p2 = sketch.add_point(Some("q2".to_string()));
+    let p = sketch.add_point(Some("q".to_string()));
+    let line = sketch.add_line(p1, p2, Some("line2".to_string()));
+
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(1.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(4.0),
+        Length::meters(1.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p,
+        Length::meters(2.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(PointSideOfLineConstraint::new(line, p, Side::Left));
+
+    assert!(matches!(
+        sketch.solve_and_extract(),
+        Err(TextCadError::OverConstrained)
+    ));
+}
+
+#[test]
+fn test_corner_angle_right_angle_at_origin() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Two lines leaving a shared vertex at the origin
+    let origin = sketch.add_point(Some("origin".to_string()));
+    let a = sketch.add_point(Some("a".to_string()));
+    let b = sketch.add_point(Some("b".to_string()));
+    let line1 = sketch.add_line(origin, a, Some("line1".to_string()));
+    let line2 = sketch.add_line(b, origin, Some("line2".to_string()));
+
+    sketch.add_constraint(FixedPositionConstraint::new(
+        origin,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a,
+        Length::meters(2.0),
+        Length::meters(1.0),
+    ));
+    sketch.add_constraint(LineLengthConstraint::new(line2, Length::meters(1.0)));
+    sketch.add_constraint(CornerAngleConstraint::new(
+        line1,
+        line2,
+        origin,
+        Angle::degrees(90.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let (ox, oy) = solution.get_point_coordinates(origin).unwrap();
+    let (ax, ay) = solution.get_point_coordinates(a).unwrap();
+    let (bx, by) = solution.get_point_coordinates(b).unwrap();
+
+    // Outgoing directions are perpendicular and line2 is non-degenerate
+    let dot = (ax - ox) * (bx - ox) + (ay - oy) * (by - oy);
+    assert!(dot.abs() < 1e-6);
+    assert!(((bx - ox).hypot(by - oy) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_corner_angle_rejects_lines_without_shared_point() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(None);
+    let p2 = sketch.add_point(None);
+    let p3 = sketch.add_point(None);
+    let p4 = sketch.add_point(None);
+    let line1 = sketch.add_line(p1, p2, None);
+    let line2 = sketch.add_line(p3, p4, None);
+
+    sketch.add_constraint(CornerAngleConstraint::new(
+        line1,
+        line2,
+        p1,
+        Angle::degrees(45.0),
+    ));
+
+    assert!(matches!(
+        sketch.solve_and_extract(),
+        Err(TextCadError::InvalidConstraint(_))
+    ));
+}
+
+#[test]
+fn test_offset_line_lands_on_requested_side() {
+    for (side, expected_y) in [(OffsetSide::Left, 1.5), (OffsetSide::Right, -1.5)] {
+        // Repeat to make sure the side does not depend on solver luck
+        for _ in 0..3 {
+            let cfg = Config::new();
+            let ctx = Context::new(&cfg);
+            let mut sketch = Sketch::new(&ctx);
+
+            // Base line along +x
+            let a = sketch.add_point(Some("a".to_string()));
+            let b = sketch.add_point(Some("b".to_string()));
+            let base = sketch.add_line(a, b, Some("base".to_string()));
+            sketch.add_constraint(FixedPositionConstraint::new(
+                a,
+                Length::meters(0.0),
+                Length::meters(0.0),
+            ));
+            sketch.add_constraint(FixedPositionConstraint::new(
+                b,
+                Length::meters(4.0),
+                Length::meters(0.0),
+            ));
+
+            let p = sketch.add_point(Some("p".to_string()));
+            let q = sketch.add_point(Some("q".to_string()));
+            let offset = sketch.add_line(p, q, Some("offset".to_string()));
+            sketch.add_constraint(OffsetLineConstraint::new(
+                base,
+                offset,
+                Length::meters(1.5),
+                side,
+            ));
+
+            let solution = sketch.solve_and_extract().unwrap();
+            let (_, py) = solution.get_point_coordinates(p).unwrap();
+            let (_, qy) = solution.get_point_coordinates(q).unwrap();
+            assert!(
+                (py - expected_y).abs() < 1e-6,
+                "{:?} side: p.y = {}",
+                side,
+                py
+            );
+            assert!(
+                (qy - expected_y).abs() < 1e-6,
+                "{:?} side: q.y = {}",
+                side,
+                qy
+            );
+        }
+    }
+}
+
+/// Add a line whose start is fixed at the origin, returning its end point and ID
+fn max_length_sketch(sketch: &mut Sketch<'_>) -> (textcad::PointId, textcad::LineId) {
+    let a = sketch.add_point(Some("a".to_string()));
+    let b = sketch.add_point(Some("b".to_string()));
+    let line = sketch.add_line(a, b, Some("line".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    (b, line)
+}
+
+#[test]
+fn test_max_length_caps_pulled_line() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    // Reference solution with the endpoint at (10, 0), well beyond 5m
+    let mut reference = Sketch::new(&ctx);
+    let (b, _) = max_length_sketch(&mut reference);
+    reference.add_constraint(FixedPositionConstraint::new(
+        b,
+        Length::meters(10.0),
+        Length::meters(0.0),
+    ));
+    let previous = reference.solve_and_extract().unwrap();
+
+    // Same layout with a 5m cap, warm-started towards the reference
+    let mut sketch = Sketch::new(&ctx);
+    let (_, line) = max_length_sketch(&mut sketch);
+    sketch.add_constraint(MaxLengthConstraint::new(line, Length::meters(5.0)));
+    let solution = sketch.solve_warm(&previous).unwrap();
+
+    let params = solution.get_line_parameters(line).unwrap();
+    assert!(params.length <= 5.0 + 1e-6);
+}
+
+#[test]
+fn test_max_length_unsat_when_fixed_longer() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let (b, line) = max_length_sketch(&mut sketch);
+    sketch.add_constraint(FixedPositionConstraint::new(
+        b,
+        Length::meters(6.0),
+        Length::meters(8.0),
+    ));
+    sketch.add_constraint(MaxLengthConstraint::new(line, Length::meters(5.0)));
+
+    assert!(matches!(
+        sketch.solve_and_extract(),
+        Err(TextCadError::OverConstrained)
+    ));
+}
+
+#[test]
+fn test_through_midpoint_of_fixed_segment() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Fixed segment from (0,0) to (4,2), midpoint (2,1)
+    let seg_a = sketch.add_point(Some("seg_a".to_string()));
+    let seg_b = sketch.add_point(Some("seg_b".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        seg_a,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        seg_b,
+        Length::meters(4.0),
+        Length::meters(2.0),
+    ));
+
+    // Line from (0,3) whose end sits at y = -1
+    let start = sketch.add_point(Some("start".to_string()));
+    let end = sketch.add_point(Some("end".to_string()));
+    let line = sketch.add_line(start, end, Some("cutter".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        start,
+        Length::meters(0.0),
+        Length::meters(3.0),
+    ));
+    sketch.add_constraint(VerticalDistanceConstraint::new(
+        start,
+        end,
+        Length::meters(-4.0),
+    ));
+
+    sketch.add_constraint(ThroughMidpointConstraint::new(line, seg_a, seg_b));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let params = solution.get_line_parameters(line).unwrap();
+    let (x1, y1) = params.start;
+    let (x2, y2) = params.end;
+
+    // Midpoint (2,1) is collinear with the line's endpoints
+    let cross = (x2 - x1) * (1.0 - y1) - (y2 - y1) * (2.0 - x1);
+    assert!(cross.abs() < 1e-6);
+    assert!((x2 - 4.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_codirectional_excludes_flipped_direction() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    // Reference line from (0,0) to (1,0), and a second line of length 2
+    // starting at (0,1)
+    let build = || {
+        let mut sketch = Sketch::new(&ctx);
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let p3 = sketch.add_point(Some("p3".to_string()));
+        let p4 = sketch.add_point(Some("p4".to_string()));
+        let line1 = sketch.add_line(p1, p2, Some("reference".to_string()));
+        let line2 = sketch.add_line(p3, p4, Some("arrow".to_string()));
+        for (point, x, y) in [(p1, 0.0, 0.0), (p2, 1.0, 0.0), (p3, 0.0, 1.0)] {
+            sketch.add_constraint(FixedPositionConstraint::new(
+                point,
+                Length::meters(x),
+                Length::meters(y),
+            ));
+        }
+        sketch.add_constraint(LineLengthConstraint::new(line2, Length::meters(2.0)));
+        (sketch, line1, line2, p4)
+    };
+
+    let (mut sketch, line1, line2, _) = build();
+    sketch.add_constraint(CodirectionalConstraint::new(line1, line2));
+    let solution = sketch.solve_and_extract().unwrap();
+    let reference = solution.get_line_parameters(line1).unwrap();
+    let arrow = solution.get_line_parameters(line2).unwrap();
+    let dot = (reference.end.0 - reference.start.0) * (arrow.end.0 - arrow.start.0)
+        + (reference.end.1 - reference.start.1) * (arrow.end.1 - arrow.start.1);
+    assert!(dot > 0.0);
+    assert!((arrow.end.0 - 2.0).abs() < 1e-6);
+    assert!((arrow.end.1 - 1.0).abs() < 1e-6);
+
+    // Pinning the arrow backwards to (-2,1) is allowed by a parallel
+    // constraint, but not by a codirectional one
+    let (mut parallel, line1, line2, end) = build();
+    parallel.add_constraint(ParallelLinesConstraint::new(line1, line2));
+    parallel.add_constraint(FixedPositionConstraint::new(
+        end,
+        Length::meters(-2.0),
+        Length::meters(1.0),
+    ));
+    assert!(parallel.is_satisfiable().unwrap());
+
+    let (mut codirectional, line1, line2, end) = build();
+    codirectional.add_constraint(CodirectionalConstraint::new(line1, line2));
+    codirectional.add_constraint(FixedPositionConstraint::new(
+        end,
+        Length::meters(-2.0),
+        Length::meters(1.0),
+    ));
+    assert!(!codirectional.is_satisfiable().unwrap());
+}
+
+#[test]
+fn test_inclination_zero_points_straight_down() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let top = sketch.add_point(Some("top".to_string()));
+    let bottom = sketch.add_point(Some("bottom".to_string()));
+    let column = sketch.add_line(top, bottom, Some("column".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        top,
+        Length::meters(1.0),
+        Length::meters(5.0),
+    ));
+    sketch.add_constraint(InclinationConstraint::new(column, Angle::degrees(0.0)));
+    sketch.add_constraint(LineLengthConstraint::new(column, Length::meters(3.0)));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.get_point_coordinates(bottom).unwrap();
+
+    assert!((x - 1.0).abs() < 1e-6);
+    assert!((y - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_equal_inclination_matches_slope_magnitude() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Left rafter rising at +30 degrees
+    let (cos30, sin30) = (30f64.to_radians().cos(), 30f64.to_radians().sin());
+    let eave = sketch.add_point(Some("eave".to_string()));
+    let ridge = sketch.add_point(Some("ridge".to_string()));
+    sketch.fix_points(&[
+        (eave, Length::meters(0.0), Length::meters(0.0)),
+        (
+            ridge,
+            Length::meters(2.0 * cos30),
+            Length::meters(2.0 * sin30),
+        ),
+    ]);
+    let left = sketch.add_line(eave, ridge, Some("left".to_string()));
+
+    // Right rafter from the ridge with only its length fixed
+    let far_eave = sketch.add_point(Some("far_eave".to_string()));
+    let right = sketch.add_line(ridge, far_eave, Some("right".to_string()));
+    sketch.add_constraint(LineLengthConstraint::new(right, Length::meters(2.0)));
+    sketch.add_constraint(EqualInclinationConstraint::new(left, right));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let params = solution.get_line_parameters(right).unwrap();
+    let (dx, dy) = (params.end.0 - params.start.0, params.end.1 - params.start.1);
+
+    // +30 or -30 degrees from horizontal, in either direction
+    let inclination = (dy.abs() / dx.abs()).atan().to_degrees();
+    assert!((inclination - 30.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_angle_range_clamps_to_upper_bound() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let pivot = sketch.add_point(Some("pivot".to_string()));
+    let tip = sketch.add_point(Some("tip".to_string()));
+    let arm = sketch.add_line(pivot, tip, Some("arm".to_string()));
+    sketch.fix_points(&[(pivot, Length::meters(0.0), Length::meters(0.0))]);
+    sketch.add_constraint(LineLengthConstraint::new(arm, Length::meters(2.0)));
+    sketch.add_constraint(AngleRangeConstraint::new(
+        arm,
+        Angle::degrees(0.0),
+        Angle::degrees(90.0),
+    ));
+
+    // Pull the arm toward 120°: the tip must lie at or beyond the vertical
+    let tip_x = sketch.get_point(tip).unwrap().x.clone();
+    sketch
+        .solver_mut()
+        .assert(&tip_x.le(&Real::from_real(&ctx, 0, 1)));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.get_point_coordinates(tip).unwrap();
+
+    // Clamped to the 90° boundary
+    assert!(x.abs() < 1e-6);
+    assert!((y - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_angle_range_rejects_reflex_wedge() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(None);
+    let p2 = sketch.add_point(None);
+    let line = sketch.add_line(p1, p2, None);
+    sketch.add_constraint(AngleRangeConstraint::new(
+        line,
+        Angle::degrees(0.0),
+        Angle::degrees(270.0),
+    ));
+
+    assert!(matches!(
+        sketch.solve(),
+        Err(TextCadError::InvalidConstraint(_))
+    ));
+}
@@ -5,7 +5,7 @@
 
 use textcad::constraints::{
     CoincidentPointsConstraint, FixedPositionConstraint, LineLengthConstraint,
-    ParallelLinesConstraint, PerpendicularLinesConstraint,
+    ParallelLinesConstraint, PerpendicularLinesConstraint, VerticalDistanceConstraint,
 };
 use textcad::error::TextCadError;
 use textcad::sketch::Sketch;
@@ -629,3 +629,99 @@ fn test_performance_many_line_constraints() {
         elapsed
     );
 }
+
+/// Test that long lines use the exact rational length instead of an overflowing i32 encoding
+#[test]
+fn test_line_length_exact_rational_large_length() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let line = sketch.add_line(p1, p2, Some("line".to_string()));
+
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(VerticalDistanceConstraint::new(p1, p2, Length::meters(0.0)));
+    // 2000² * 1e6 overflows i32, so this relies on the exact rational path
+    sketch.add_constraint(LineLengthConstraint::new(line, Length::meters(2000.0)));
+
+    let solution = sketch.solve_and_extract().expect("Long line should solve");
+
+    let params = solution.get_line_parameters(line).unwrap();
+    assert!((params.length - 2000.0).abs() < 1e-6);
+}
+
+/// Test that large f64 lengths no longer overflow the rational encoding
+#[test]
+fn test_line_length_large_values_do_not_overflow() {
+    // Integral values take the exact path; fractional values take the f64 path
+    for target in [100.0, 100.25, 10_000.0, 10_000.5] {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let line = sketch.add_line(p1, p2, Some("line".to_string()));
+
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p1,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(VerticalDistanceConstraint::new(p1, p2, Length::meters(0.0)));
+        sketch.add_constraint(LineLengthConstraint::new(line, Length::meters(target)));
+
+        let solution = sketch
+            .solve_and_extract()
+            .expect("Large line length should solve");
+
+        let params = solution.get_line_parameters(line).unwrap();
+        assert!(
+            (params.length - target).abs() < 1e-6,
+            "Expected length {}, got {}",
+            target,
+            params.length
+        );
+    }
+}
+
+/// Test that millimetre and micrometre lengths keep their precision
+#[test]
+fn test_line_length_small_values_keep_precision() {
+    // Squared, these all fall below a 1e-6 rounding step
+    for target in [0.0005, 0.0015, 2.5e-6, 1e-9] {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        let line = sketch.add_line(p1, p2, Some("line".to_string()));
+
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p1,
+            Length::meters(0.0),
+            Length::meters(0.0),
+        ));
+        sketch.add_constraint(VerticalDistanceConstraint::new(p1, p2, Length::meters(0.0)));
+        sketch.add_constraint(LineLengthConstraint::new(line, Length::meters(target)));
+
+        let solution = sketch
+            .solve_and_extract()
+            .expect("Small line length should solve");
+
+        let params = solution.get_line_parameters(line).unwrap();
+        assert!(
+            ((params.length - target) / target).abs() < 1e-9,
+            "Expected length {}, got {}",
+            target,
+            params.length
+        );
+    }
+}
@@ -3,7 +3,12 @@
 //! Tests complete workflows including sketch creation, constraint application,
 //! solving, and solution extraction for the PointOnLineConstraint.
 
-use textcad::constraints::{FixedPositionConstraint, PointOnLineConstraint};
+use textcad::constraints::{
+    DistanceAlongLineConstraint, FixedPositionConstraint, HorizontalDistanceConstraint,
+    MidpointOnLineConstraint, OnAngleBisectorConstraint, PointOnLineConstraint,
+    PointSideOfLineConstraint, ProjectionConstraint, ProportionalPointConstraint, Side,
+    VerticalDistanceConstraint,
+};
 use textcad::error::TextCadError;
 use textcad::sketch::Sketch;
 use textcad::units::Length;
@@ -301,3 +306,210 @@ fn test_point_on_line_constraint_combined_with_other_constraints() {
     let expected_t = 2.0 / 6.0;
     assert!((expected_t - 1.0f64 / 3.0f64).abs() < 1e-6);
 }
+
+#[test]
+fn test_projection_constraint_foot_of_perpendicular() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Line along y = 0
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let line = sketch.add_line(p1, p2, Some("base".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(10.0),
+        Length::meters(0.0),
+    ));
+
+    let source = sketch.add_point(Some("source".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        source,
+        Length::meters(3.0),
+        Length::meters(4.0),
+    ));
+
+    let foot = sketch.add_point(Some("foot".to_string()));
+    sketch.add_constraint(ProjectionConstraint::new(source, line, foot));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (fx, fy) = solution.get_point_coordinates(foot).unwrap();
+    assert!((fx - 3.0).abs() < 1e-6);
+    assert!(fy.abs() < 1e-6);
+}
+
+#[test]
+fn test_midpoint_on_line_forms_t_junction() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Vertical stem from (0,0) to (0,4)
+    let stem_start = sketch.add_point(Some("stem_start".to_string()));
+    let stem_end = sketch.add_point(Some("stem_end".to_string()));
+    let stem = sketch.add_line(stem_start, stem_end, Some("stem".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        stem_start,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        stem_end,
+        Length::meters(0.0),
+        Length::meters(4.0),
+    ));
+
+    // Horizontal crossbar with its left end fixed at (-1, 2)
+    let left = sketch.add_point(Some("left".to_string()));
+    let right = sketch.add_point(Some("right".to_string()));
+    let crossbar = sketch.add_line(left, right, Some("crossbar".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        left,
+        Length::meters(-1.0),
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(VerticalDistanceConstraint::new(
+        left,
+        right,
+        Length::meters(0.0),
+    ));
+
+    sketch.add_constraint(MidpointOnLineConstraint::new(crossbar, stem));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (lx, ly) = solution.get_point_coordinates(left).unwrap();
+    let (rx, ry) = solution.get_point_coordinates(right).unwrap();
+    let (mx, my) = ((lx + rx) / 2.0, (ly + ry) / 2.0);
+
+    // Midpoint is collinear with the stem: (m - start) × (end - start) = 0
+    let (sx, sy) = solution.get_point_coordinates(stem_start).unwrap();
+    let (ex, ey) = solution.get_point_coordinates(stem_end).unwrap();
+    let cross = (mx - sx) * (ey - sy) - (my - sy) * (ex - sx);
+    assert!(cross.abs() < 1e-6);
+    assert!((0.0..=4.0).contains(&my));
+    assert!((rx - 1.0).abs() < 1e-6);
+    assert!((ry - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_proportional_point_golden_section() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let a = sketch.add_point(Some("a".to_string()));
+    let b = sketch.add_point(Some("b".to_string()));
+    let c = sketch.add_point(Some("c".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        c,
+        Length::meters(10.0),
+        Length::meters(5.0),
+    ));
+    sketch.add_constraint(ProportionalPointConstraint::new(a, b, c, 1.618));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (bx, by) = solution.get_point_coordinates(b).unwrap();
+
+    let t = 1.618 / 2.618;
+    assert!((bx - 10.0 * t).abs() < 1e-6);
+    assert!((by - 5.0 * t).abs() < 1e-6);
+
+    // AB:BC matches the ratio
+    let ab = (bx.powi(2) + by.powi(2)).sqrt();
+    let bc = ((10.0 - bx).powi(2) + (5.0 - by).powi(2)).sqrt();
+    assert!((ab / bc - 1.618).abs() < 1e-6);
+}
+
+#[test]
+fn test_distance_along_line_from_start() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // 5m line from (0,0) to (3,4)
+    let start = sketch.add_point(Some("start".to_string()));
+    let end = sketch.add_point(Some("end".to_string()));
+    let line = sketch.add_line(start, end, Some("line".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        start,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        end,
+        Length::meters(3.0),
+        Length::meters(4.0),
+    ));
+
+    let from_start = sketch.add_point(Some("from_start".to_string()));
+    let from_end = sketch.add_point(Some("from_end".to_string()));
+    sketch.add_constraint(DistanceAlongLineConstraint::new(
+        line,
+        true,
+        from_start,
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(DistanceAlongLineConstraint::new(
+        line,
+        false,
+        from_end,
+        Length::meters(2.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    // 2/5 of the way along from either end
+    let (x, y) = solution.get_point_coordinates(from_start).unwrap();
+    assert!((x - 1.2).abs() < 1e-6);
+    assert!((y - 1.6).abs() < 1e-6);
+    let (x, y) = solution.get_point_coordinates(from_end).unwrap();
+    assert!((x - 1.8).abs() < 1e-6);
+    assert!((y - 2.4).abs() < 1e-6);
+}
+
+#[test]
+fn test_on_angle_bisector_of_right_angle() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // x- and y-axis segments meeting at the origin
+    let origin = sketch.add_point(Some("origin".to_string()));
+    let on_x = sketch.add_point(Some("on_x".to_string()));
+    let on_y = sketch.add_point(Some("on_y".to_string()));
+    sketch.fix_points(&[
+        (origin, Length::meters(0.0), Length::meters(0.0)),
+        (on_x, Length::meters(1.0), Length::meters(0.0)),
+        (on_y, Length::meters(0.0), Length::meters(1.0)),
+    ]);
+    let x_axis = sketch.add_line(origin, on_x, Some("x_axis".to_string()));
+    let y_axis = sketch.add_line(origin, on_y, Some("y_axis".to_string()));
+
+    // Point 2m right of the origin, above the x-axis
+    let point = sketch.add_point(Some("p".to_string()));
+    sketch.add_constraint(HorizontalDistanceConstraint::new(
+        origin,
+        point,
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(PointSideOfLineConstraint::new(x_axis, point, Side::Left));
+    sketch.add_constraint(OnAngleBisectorConstraint::new(x_axis, y_axis, point));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.get_point_coordinates(point).unwrap();
+
+    // On the 45-degree bisector
+    assert!((x - 2.0).abs() < 1e-6);
+    assert!((y - x).abs() < 1e-6);
+}
@@ -0,0 +1,329 @@
+//! Integration tests for polygon constraints
+//!
+//! Tests closing chains of independently created lines, bounding enclosed
+//! areas and perimeters, and completing quadrilaterals through the sketch
+//! solver, verifying the solved positions.
+
+use textcad::constraints::{
+    ClosedLoopConstraint, ConvexPolygonConstraint, EqualDiagonalsConstraint,
+    FixedPositionConstraint, HorizontalDistanceConstraint, MinAreaConstraint,
+    ParallelogramConstraint, PerimeterConstraint, RhombusConstraint, TriangleAreaConstraint,
+    VerticalDistanceConstraint,
+};
+use textcad::error::TextCadError;
+use textcad::sketch::Sketch;
+use textcad::units::{Area, Length};
+use z3::ast::Real;
+use z3::{Config, Context};
+
+#[test]
+fn test_closed_loop_triangle_integration() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Three lines with six independent endpoints
+    let a1 = sketch.add_point(Some("a1".to_string()));
+    let a2 = sketch.add_point(Some("a2".to_string()));
+    let b1 = sketch.add_point(Some("b1".to_string()));
+    let b2 = sketch.add_point(Some("b2".to_string()));
+    let c1 = sketch.add_point(Some("c1".to_string()));
+    let c2 = sketch.add_point(Some("c2".to_string()));
+
+    let line_a = sketch.add_line(a1, a2, Some("a".to_string()));
+    let line_b = sketch.add_line(b1, b2, Some("b".to_string()));
+    let line_c = sketch.add_line(c1, c2, Some("c".to_string()));
+
+    // Fix the start of each line at a triangle vertex
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        b1,
+        Length::meters(4.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        c1,
+        Length::meters(0.0),
+        Length::meters(3.0),
+    ));
+
+    sketch.add_constraint(ClosedLoopConstraint::new(vec![line_a, line_b, line_c]));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    // Each end point lands on the next line's start
+    let pairs = [(a2, b1), (b2, c1), (c2, a1)];
+    for (end, next_start) in pairs {
+        let (ex, ey) = solution.get_point_coordinates(end).unwrap();
+        let (sx, sy) = solution.get_point_coordinates(next_start).unwrap();
+        assert!((ex - sx).abs() < 1e-6);
+        assert!((ey - sy).abs() < 1e-6);
+    }
+
+    // The hypotenuse runs from (4, 0) to (0, 3)
+    let params = solution.get_line_parameters(line_b).unwrap();
+    assert!((params.length - 5.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_min_area_quad_with_free_corner() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p0 = sketch.add_point(Some("p0".to_string()));
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let p3 = sketch.add_point(Some("p3".to_string()));
+
+    // Fixed triangle corners enclose only 0.5 m²
+    for (p, x, y) in [(p0, 0.0, 0.0), (p1, 1.0, 0.0), (p2, 1.0, 1.0)] {
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p,
+            Length::meters(x),
+            Length::meters(y),
+        ));
+    }
+
+    sketch.add_constraint(MinAreaConstraint::new(
+        vec![p0, p1, p2, p3],
+        Area::square_meters(2.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let corners: Vec<(f64, f64)> = [p0, p1, p2, p3]
+        .iter()
+        .map(|&p| solution.get_point_coordinates(p).unwrap())
+        .collect();
+    let twice_area: f64 = (0..corners.len())
+        .map(|i| {
+            let (xi, yi) = corners[i];
+            let (xj, yj) = corners[(i + 1) % corners.len()];
+            xi * yj - xj * yi
+        })
+        .sum();
+
+    assert!(twice_area.abs() / 2.0 >= 2.0 - 1e-9);
+}
+
+#[test]
+fn test_perimeter_triangle_with_fixed_side() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Base fixed from (0,0) to (3,0); apex free
+    let a = sketch.add_point(Some("a".to_string()));
+    let b = sketch.add_point(Some("b".to_string()));
+    let c = sketch.add_point(Some("c".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        b,
+        Length::meters(3.0),
+        Length::meters(0.0),
+    ));
+
+    let sides = vec![
+        sketch.add_line(a, b, Some("ab".to_string())),
+        sketch.add_line(b, c, Some("bc".to_string())),
+        sketch.add_line(c, a, Some("ca".to_string())),
+    ];
+    sketch.add_constraint(PerimeterConstraint::new(
+        sides.clone(),
+        Length::meters(12.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let perimeter: f64 = sides
+        .iter()
+        .map(|&side| solution.get_line_parameters(side).unwrap().length)
+        .sum();
+    assert!((perimeter - 12.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_triangle_area_with_free_apex() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Base fixed from (0,0) to (4,0); apex free
+    let a = sketch.add_point(Some("a".to_string()));
+    let b = sketch.add_point(Some("b".to_string()));
+    let c = sketch.add_point(Some("c".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        b,
+        Length::meters(4.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(TriangleAreaConstraint::new(
+        a,
+        b,
+        c,
+        Area::square_meters(6.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (ax, ay) = solution.get_point_coordinates(a).unwrap();
+    let (bx, by) = solution.get_point_coordinates(b).unwrap();
+    let (cx, cy) = solution.get_point_coordinates(c).unwrap();
+
+    let area = ((bx - ax) * (cy - ay) - (by - ay) * (cx - ax)).abs() / 2.0;
+    assert!((area - 6.0).abs() < 1e-6);
+    // With a 4m base the apex sits 3m off the x-axis
+    assert!((cy.abs() - 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_triangle_area_rejects_negative_area() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let a = sketch.add_point(None);
+    let b = sketch.add_point(None);
+    let c = sketch.add_point(None);
+    sketch.add_constraint(TriangleAreaConstraint::new(
+        a,
+        b,
+        c,
+        Area::square_meters(-2.0),
+    ));
+
+    assert!(matches!(
+        sketch.solve_and_extract(),
+        Err(TextCadError::InvalidConstraint(_))
+    ));
+}
+
+#[test]
+fn test_parallelogram_completes_fourth_corner() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let fixed = [(0.0, 0.0), (4.0, 0.0), (5.0, 2.0)];
+    let mut vertices = Vec::new();
+    for (i, &(x, y)) in fixed.iter().enumerate() {
+        let v = sketch.add_point(Some(format!("v{}", i)));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            v,
+            Length::meters(x),
+            Length::meters(y),
+        ));
+        vertices.push(v);
+    }
+    let fourth = sketch.add_point(Some("v3".to_string()));
+    vertices.push(fourth);
+
+    sketch.add_constraint(ParallelogramConstraint::new([
+        vertices[0],
+        vertices[1],
+        vertices[2],
+        vertices[3],
+    ]));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.get_point_coordinates(fourth).unwrap();
+    assert!((x - 1.0).abs() < 1e-6);
+    assert!((y - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_equal_diagonals_completes_rectangle() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Two sides of a 4x3 rectangle; the third side is horizontal
+    let fixed = [(0.0, 0.0), (4.0, 0.0), (4.0, 3.0)];
+    let mut vertices = Vec::new();
+    for (i, &(x, y)) in fixed.iter().enumerate() {
+        let v = sketch.add_point(Some(format!("v{}", i)));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            v,
+            Length::meters(x),
+            Length::meters(y),
+        ));
+        vertices.push(v);
+    }
+    let fourth = sketch.add_point(Some("v3".to_string()));
+    vertices.push(fourth);
+    sketch.add_constraint(VerticalDistanceConstraint::new(
+        vertices[2],
+        fourth,
+        Length::meters(0.0),
+    ));
+
+    let quad = [vertices[0], vertices[1], vertices[2], vertices[3]];
+    sketch.add_constraint(EqualDiagonalsConstraint::new(quad));
+    // Rules out the crossed candidate at (8, 3)
+    sketch.add_constraint(ConvexPolygonConstraint::new(quad.to_vec()));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.get_point_coordinates(fourth).unwrap();
+    assert!(x.abs() < 1e-6);
+    assert!((y - 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_rhombus_completes_equilateral_quadrilateral() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Fixed side of length 2 along the x-axis
+    let v0 = sketch.add_point(Some("v0".to_string()));
+    let v1 = sketch.add_point(Some("v1".to_string()));
+    sketch.fix_points(&[
+        (v0, Length::meters(0.0), Length::meters(0.0)),
+        (v1, Length::meters(2.0), Length::meters(0.0)),
+    ]);
+    let v2 = sketch.add_point(Some("v2".to_string()));
+    let v3 = sketch.add_point(Some("v3".to_string()));
+
+    // Lean the side from v0 to v3 one meter over, above the x-axis
+    sketch.add_constraint(HorizontalDistanceConstraint::new(
+        v0,
+        v3,
+        Length::meters(1.0),
+    ));
+    let v3_y = sketch.get_point(v3).unwrap().y.clone();
+    sketch
+        .solver_mut()
+        .assert(&v3_y.gt(&Real::from_real(&ctx, 0, 1)));
+
+    let quad = [v0, v1, v2, v3];
+    sketch.add_constraint(RhombusConstraint::new(quad));
+    // Rules out the degenerate candidate with v2 on v0
+    sketch.add_constraint(ConvexPolygonConstraint::new(quad.to_vec()));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let sqrt3 = 3f64.sqrt();
+    let (x3, y3) = solution.get_point_coordinates(v3).unwrap();
+    assert!((x3 - 1.0).abs() < 1e-6);
+    assert!((y3 - sqrt3).abs() < 1e-6);
+    let (x2, y2) = solution.get_point_coordinates(v2).unwrap();
+    assert!((x2 - 3.0).abs() < 1e-6);
+    assert!((y2 - sqrt3).abs() < 1e-6);
+
+    // All four sides have the fixed side's length
+    let points = [(0.0, 0.0), (2.0, 0.0), (x2, y2), (x3, y3)];
+    for (&(ax, ay), &(bx, by)) in points.iter().zip(points.iter().cycle().skip(1)) {
+        assert!((((bx - ax).powi(2) + (by - ay).powi(2)).sqrt() - 2.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,186 @@
+//! Integration tests for solution snapshots
+//!
+//! Tests taking a model-free snapshot of all solved geometry, freezing a
+//! solution into a fully fixed sketch (including unnamed entities and pinned
+//! circle and ellipse sizes), dumping the raw model, and evaluating
+//! expressions over stored point variables.
+
+use textcad::constraints::{
+    EllipseSemiAxesConstraint, FixedPositionConstraint, LineLengthConstraint,
+};
+use textcad::sketch::Sketch;
+use textcad::units::Length;
+use z3::ast::Real;
+use z3::{Config, Context};
+
+#[test]
+fn test_snapshot_square_with_circle() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Unit square
+    let corners = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+    let points: Vec<_> = corners
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, y))| {
+            let p = sketch.add_point(Some(format!("p{}", i)));
+            sketch.add_constraint(FixedPositionConstraint::new(
+                p,
+                Length::meters(x),
+                Length::meters(y),
+            ));
+            p
+        })
+        .collect();
+
+    for i in 0..4 {
+        let line = sketch.add_line(points[i], points[(i + 1) % 4], Some(format!("l{}", i)));
+        sketch.add_constraint(LineLengthConstraint::new(line, Length::meters(1.0)));
+    }
+
+    // Circle centered in the square
+    let center = sketch.add_point(Some("center".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(0.5),
+        Length::meters(0.5),
+    ));
+    let circle = sketch.add_circle(center, Some("circle".to_string()));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let snapshot = solution.snapshot();
+
+    assert_eq!(snapshot.points.len(), 5);
+    assert_eq!(snapshot.lines.len(), 4);
+    assert_eq!(snapshot.circles.len(), 1);
+
+    // Snapshot values match the cached solution values
+    for (line_id, params) in &snapshot.lines {
+        assert_eq!(*params, solution.get_line_parameters(*line_id).unwrap());
+        assert!((params.length - 1.0).abs() < 1e-6);
+    }
+    let (circle_id, params) = snapshot.circles[0];
+    assert_eq!(circle_id, circle);
+    assert_eq!(params.center, (0.5, 0.5));
+}
+
+#[test]
+fn test_to_fixed_sketch_reproduces_rectangle() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let origin = sketch.add_point(Some("origin".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        origin,
+        Length::meters(1.0),
+        Length::meters(2.0),
+    ));
+    let (corners, _) = sketch.add_rectangle(origin, Length::meters(4.0), Length::meters(3.0));
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let frozen_ctx = Context::new(&cfg);
+    let mut frozen = solution.to_fixed_sketch(&frozen_ctx, &sketch).unwrap();
+    assert_eq!(frozen.points().count(), 4);
+    assert_eq!(frozen.lines().count(), 4);
+    assert_eq!(frozen.free_coordinates().unwrap(), Vec::new());
+
+    let refrozen = frozen.solve_and_extract().unwrap();
+    assert!(refrozen.approx_eq(&solution, 1e-5));
+    for corner in corners {
+        assert!(refrozen.get_point_coordinates(corner).is_ok());
+    }
+}
+
+#[test]
+fn test_to_fixed_sketch_unnamed_entities_survive_solver_reset() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let a = sketch.add_point(None);
+    let b = sketch.add_point(None);
+    sketch.fix_points(&[
+        (a, Length::meters(0.0), Length::meters(0.0)),
+        (b, Length::meters(3.0), Length::meters(-1.0)),
+    ]);
+    let circle = sketch.add_circle_with_radius(a, Length::meters(2.0), None);
+    let ellipse = sketch.add_ellipse(b, None);
+    sketch.add_constraint(EllipseSemiAxesConstraint::new(
+        ellipse,
+        Length::meters(1.5),
+        Length::meters(0.5),
+    ));
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let frozen_ctx = Context::new(&cfg);
+    let mut frozen = solution.to_fixed_sketch(&frozen_ctx, &sketch).unwrap();
+
+    // Removing an entity resets the solver; the pinned sizes must survive
+    let spare = frozen.add_point(None);
+    frozen.remove_point(spare).unwrap();
+
+    let refrozen = frozen.solve_and_extract().unwrap();
+    assert_eq!(refrozen.get_point_coordinates(b).unwrap(), (3.0, -1.0));
+    assert!((refrozen.get_circle_parameters(circle).unwrap().radius - 2.0).abs() < 1e-9);
+    let shape = frozen.get_ellipse(ellipse).unwrap();
+    assert!((refrozen.eval_real(&shape.semi_major).unwrap() - 1.5).abs() < 1e-9);
+    assert!((refrozen.eval_real(&shape.semi_minor).unwrap() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_model_dump_lists_point_variables() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(1.0),
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(3.0),
+        Length::meters(4.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let dump = solution.model_dump();
+
+    for name in ["p1_x", "p1_y", "p2_x", "p2_y"] {
+        assert!(
+            dump.iter().any(|(decl, _)| decl == name),
+            "{} missing from {:?}",
+            name,
+            dump
+        );
+    }
+    let (_, p2_x) = dump.iter().find(|(decl, _)| decl == "p2_x").unwrap();
+    assert_eq!(p2_x, "3.0");
+}
+
+#[test]
+fn test_point_variable_reevaluates_after_solving() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p = sketch.add_point(Some("p".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p,
+        Length::meters(1.5),
+        Length::meters(-2.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let (x, y) = solution.point_variable(p).unwrap();
+
+    assert!((solution.eval_real(x).unwrap() - 1.5).abs() < 1e-9);
+    let sum = Real::add(&ctx, &[x, y]);
+    assert!((solution.eval_real(&sum).unwrap() + 0.5).abs() < 1e-9);
+}
@@ -0,0 +1,278 @@
+//! Integration tests for SVG export
+//!
+//! Tests exporting solved sketches to SVG, including coordinate
+//! transformation, affine transforms, per-entity offsets applied at export
+//! time, and compositing several sketches into one document.
+
+use textcad::constraints::FixedPositionConstraint;
+use textcad::entities::EntityMetadata;
+use textcad::export::{Exporter, SVGExporter};
+use textcad::sketch::Sketch;
+use textcad::units::Length;
+use z3::{Config, Context};
+
+#[test]
+fn test_svg_export_simple_line() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(0.1), // 10cm
+        Length::meters(0.1),
+    ));
+
+    sketch.add_line(p1, p2, None);
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let svg = SVGExporter::new().export(&sketch, &solution).unwrap();
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains(r#"<line x1="0.00" y1="0.00" x2="100.00" y2="-100.00""#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn test_svg_export_with_translation_transform() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(0.5),
+        Length::meters(0.0),
+    ));
+
+    sketch.add_line(p1, p2, None);
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    // Translate by (1, 0) meters
+    let exporter = SVGExporter::new().with_transform([1.0, 0.0, 0.0, 1.0, 1.0, 0.0]);
+    let svg = exporter.export(&sketch, &solution).unwrap();
+
+    assert!(svg.contains(r#"<line x1="1000.00" y1="0.00" x2="1500.00" y2="0.00""#));
+}
+
+#[test]
+fn test_svg_export_with_instance_offset() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center1 = sketch.add_point(Some("center1".to_string()));
+    let center2 = sketch.add_point(Some("center2".to_string()));
+    for center in [center1, center2] {
+        sketch.add_constraint(FixedPositionConstraint::new(
+            center,
+            Length::meters(0.1),
+            Length::meters(0.1),
+        ));
+    }
+
+    let _c1 = sketch.add_circle(center1, Some("c1".to_string()));
+    let c2 = sketch.add_circle(center2, Some("c2".to_string()));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    // Draw the second copy 0.2m to the right
+    let exporter = SVGExporter::new().with_instance_offset(c2, 0.2, 0.0);
+    let svg = exporter.export(&sketch, &solution).unwrap();
+
+    assert!(svg.contains(r#"<circle cx="100.00" cy="-100.00""#));
+    assert!(svg.contains(r#"<circle cx="300.00" cy="-100.00""#));
+    assert_eq!(svg.matches("<circle").count(), 2);
+}
+
+/// Build a small sketch with two lines and a circle and export it
+fn export_reference_sketch() -> String {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let coords = [(0.0, 0.0), (0.3, 0.0), (0.3, 0.2)];
+    let points: Vec<_> = coords
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, y))| {
+            let point = sketch.add_point(Some(format!("p{}", i)));
+            sketch.add_constraint(FixedPositionConstraint::new(
+                point,
+                Length::meters(x),
+                Length::meters(y),
+            ));
+            point
+        })
+        .collect();
+
+    sketch.add_line(points[0], points[1], None);
+    sketch.add_line(points[1], points[2], None);
+    sketch.add_circle(points[0], None);
+
+    let solution = sketch.solve_and_extract().unwrap();
+    SVGExporter::new().export(&sketch, &solution).unwrap()
+}
+
+#[test]
+fn test_svg_export_is_deterministic() {
+    let first = export_reference_sketch();
+    let second = export_reference_sketch();
+
+    assert_eq!(first, second);
+    // Lines come out in insertion (index) order
+    let first_line = first.find(r#"x2="300.00" y2="0.00""#).unwrap();
+    let second_line = first.find(r#"x2="300.00" y2="-200.00""#).unwrap();
+    assert!(first_line < second_line);
+}
+
+#[test]
+fn test_svg_export_with_grid() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // 5m wide, 1m tall
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(5.0),
+        Length::meters(1.0),
+    ));
+    sketch.add_line(p1, p2, None);
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let svg = SVGExporter::new()
+        .with_grid(Length::meters(1.0))
+        .export(&sketch, &solution)
+        .unwrap();
+
+    // Vertical lines at x = 0..=5 and horizontal lines at y = 0 and 1
+    assert_eq!(svg.matches(r#"stroke="lightgray""#).count(), 8);
+    // Grid precedes the geometry
+    let last_grid = svg.rfind(r#"stroke="lightgray""#).unwrap();
+    let geometry = svg.find(r#"stroke="black""#).unwrap();
+    assert!(last_grid < geometry);
+}
+
+#[test]
+fn test_svg_export_with_precision() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p1,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        p2,
+        Length::meters(0.123456),
+        Length::meters(0.0),
+    ));
+    sketch.add_line(p1, p2, None);
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let svg = SVGExporter::new()
+        .with_precision(4)
+        .export(&sketch, &solution)
+        .unwrap();
+    assert!(svg.contains(r#"x2="123.4560""#));
+
+    // Default keeps two decimal places
+    let svg = SVGExporter::new().export(&sketch, &solution).unwrap();
+    assert!(svg.contains(r#"x2="123.46""#));
+}
+
+#[test]
+fn test_svg_export_line_layer_and_color() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let p1 = sketch.add_point(Some("p1".to_string()));
+    let p2 = sketch.add_point(Some("p2".to_string()));
+    let p3 = sketch.add_point(Some("p3".to_string()));
+    for (point, x) in [(p1, 0.0), (p2, 0.1), (p3, 0.2)] {
+        sketch.add_constraint(FixedPositionConstraint::new(
+            point,
+            Length::meters(x),
+            Length::meters(0.0),
+        ));
+    }
+    let cut = sketch.add_line(p1, p2, None);
+    sketch.add_line(p2, p3, None);
+    sketch
+        .set_line_metadata(cut, EntityMetadata::layer("cut").with_color("red"))
+        .unwrap();
+
+    let solution = sketch.solve_and_extract().unwrap();
+    let svg = SVGExporter::new().export(&sketch, &solution).unwrap();
+
+    assert!(svg.contains(r#"x2="100.00" y2="0.00" class="cut" stroke="red""#));
+    // Untagged lines keep the default style
+    assert!(svg.contains(r#"x2="200.00" y2="0.00" stroke="black""#));
+}
+
+#[test]
+fn test_svg_export_multi_side_by_side() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    // 10cm horizontal line from the origin
+    let single_line = || {
+        let mut sketch = Sketch::new(&ctx);
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        let p2 = sketch.add_point(Some("p2".to_string()));
+        sketch.fix_points(&[
+            (p1, Length::meters(0.0), Length::meters(0.0)),
+            (p2, Length::meters(0.1), Length::meters(0.0)),
+        ]);
+        sketch.add_line(p1, p2, None);
+        sketch
+    };
+
+    let mut left = single_line();
+    let mut right = single_line();
+    let left_solution = left.solve_and_extract().unwrap();
+    let right_solution = right.solve_and_extract().unwrap();
+
+    let svg = SVGExporter::new()
+        .export_multi(&[
+            (&left, &left_solution, (0.0, 0.0)),
+            (&right, &right_solution, (0.2, 0.0)),
+        ])
+        .unwrap();
+
+    assert_eq!(svg.matches("<line").count(), 2);
+    assert!(svg.contains(r#"<line x1="0.00" y1="0.00" x2="100.00" y2="0.00""#));
+    assert!(svg.contains(r#"<line x1="200.00" y1="0.00" x2="300.00" y2="0.00""#));
+    assert!(svg.contains(r#"viewBox="-10.00 -10.00 320.00 20.00""#));
+}
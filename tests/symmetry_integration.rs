@@ -0,0 +1,105 @@
+//! Integration tests for symmetry constraints
+//!
+//! Tests mirroring lines across an axis and spacing points radially through
+//! the sketch solver, checking the solved positions.
+
+use textcad::constraints::{
+    FixedPositionConstraint, LineSymmetryConstraint, RadialSymmetryConstraint,
+};
+use textcad::sketch::Sketch;
+use textcad::units::Length;
+use z3::{Config, Context};
+
+#[test]
+fn test_line_symmetry_across_vertical_axis() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    // Vertical axis along x = 0
+    let axis_start = sketch.add_point(Some("axis_start".to_string()));
+    let axis_end = sketch.add_point(Some("axis_end".to_string()));
+    let axis = sketch.add_line(axis_start, axis_end, Some("axis".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        axis_start,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        axis_end,
+        Length::meters(0.0),
+        Length::meters(1.0),
+    ));
+
+    // Line on the right of the axis
+    let a1 = sketch.add_point(Some("a1".to_string()));
+    let a2 = sketch.add_point(Some("a2".to_string()));
+    let line_a = sketch.add_line(a1, a2, Some("a".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a1,
+        Length::meters(1.0),
+        Length::meters(2.0),
+    ));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        a2,
+        Length::meters(3.0),
+        Length::meters(5.0),
+    ));
+
+    // Free line to be mirrored into place
+    let b1 = sketch.add_point(Some("b1".to_string()));
+    let b2 = sketch.add_point(Some("b2".to_string()));
+    let line_b = sketch.add_line(b1, b2, Some("b".to_string()));
+
+    sketch.add_constraint(LineSymmetryConstraint::new(axis, line_a, line_b));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    for (a, b) in [(a1, b1), (a2, b2)] {
+        let (ax, ay) = solution.get_point_coordinates(a).unwrap();
+        let (bx, by) = solution.get_point_coordinates(b).unwrap();
+        assert!(ax > 0.0 && bx < 0.0);
+        assert!((bx + ax).abs() < 1e-6);
+        assert!((by - ay).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_radial_symmetry_spaces_points_evenly() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let mut sketch = Sketch::new(&ctx);
+
+    let center = sketch.add_point(Some("center".to_string()));
+    sketch.add_constraint(FixedPositionConstraint::new(
+        center,
+        Length::meters(0.0),
+        Length::meters(0.0),
+    ));
+    let points: Vec<_> = (0..3)
+        .map(|i| sketch.add_point(Some(format!("p{}", i))))
+        .collect();
+
+    sketch.add_constraint(RadialSymmetryConstraint::new(
+        center,
+        points.clone(),
+        Length::meters(2.0),
+    ));
+
+    let solution = sketch.solve_and_extract().unwrap();
+
+    let angles: Vec<f64> = points
+        .iter()
+        .map(|&p| {
+            let (x, y) = solution.get_point_coordinates(p).unwrap();
+            assert!((x.hypot(y) - 2.0).abs() < 1e-4);
+            y.atan2(x).to_degrees()
+        })
+        .collect();
+
+    assert!(angles[0].abs() < 1e-3);
+    for pair in angles.windows(2) {
+        let spacing = (pair[1] - pair[0]).rem_euclid(360.0);
+        assert!((spacing - 120.0).abs() < 1e-3);
+    }
+}
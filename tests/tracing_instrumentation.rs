@@ -0,0 +1,73 @@
+//! Tests for the feature-gated tracing instrumentation
+//!
+//! Run with `cargo test --features tracing`.
+
+#![cfg(feature = "tracing")]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use textcad::constraints::FixedPositionConstraint;
+use textcad::sketch::Sketch;
+use textcad::units::Length;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use z3::{Config, Context};
+
+/// Minimal subscriber that records the names of created spans
+struct SpanRecorder {
+    names: Arc<Mutex<Vec<String>>>,
+    next_id: AtomicU64,
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.names
+            .lock()
+            .unwrap()
+            .push(span.metadata().name().to_string());
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn test_solving_emits_solve_constraints_span() {
+    let names = Arc::new(Mutex::new(Vec::new()));
+    let recorder = SpanRecorder {
+        names: Arc::clone(&names),
+        next_id: AtomicU64::new(1),
+    };
+
+    tracing::subscriber::with_default(recorder, || {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let mut sketch = Sketch::new(&ctx);
+
+        let p1 = sketch.add_point(Some("p1".to_string()));
+        sketch.add_constraint(FixedPositionConstraint::new(
+            p1,
+            Length::meters(1.0),
+            Length::meters(2.0),
+        ));
+
+        sketch.solve_and_extract().unwrap();
+    });
+
+    let names = names.lock().unwrap();
+    assert!(names.iter().any(|name| name == "solve_constraints"));
+    assert!(names.iter().any(|name| name == "solve"));
+    assert!(names.iter().any(|name| name == "extract_solution"));
+}